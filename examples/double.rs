@@ -1,4 +1,4 @@
-use bint::Bint;
+use bint::{crt, Bint};
 
 fn perms(i: u8) -> (u8, u8) {
     (i % 6, i % 10)
@@ -7,7 +7,9 @@ fn perms(i: u8) -> (u8, u8) {
 fn main() {
     let mut bint = Bint::new(30);
     for _ in 0..60 {
-        let (x, y) = perms(bint.value);
+        let (x, y) = perms(bint.value());
+        let combined = crt::combine(&[Bint::new_with_value(6, x), Bint::new_with_value(10, y)]);
+        assert_eq!(Ok(bint), combined);
         bint = bint.up();
         println!("{} {}", x, y);
     }
@@ -0,0 +1,722 @@
+#[cfg(target_has_atomic = "64")]
+use std::convert::TryFrom;
+use std::fmt;
+#[cfg(target_has_atomic = "64")]
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use crate::{Bint, BintError, Unsigned};
+
+/// The [`Unsigned`] types that have an atomic counterpart to back an [`AtomicBint`].
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64` and `usize`, on targets with atomics of that
+/// width. Like `Unsigned`, it can't be implemented outside of this crate.
+pub trait AtomicUnsigned: Unsigned {
+    /// The matching atomic integer, such as `AtomicU8` for `u8`.
+    type Atomic: fmt::Debug + Send + Sync;
+
+    /// Wraps the value in its atomic integer.
+    fn new_atomic(value: Self) -> Self::Atomic;
+
+    /// See `AtomicU8::load`.
+    fn load(atomic: &Self::Atomic, order: Ordering) -> Self;
+
+    /// See `AtomicU8::store`.
+    fn store(atomic: &Self::Atomic, value: Self, order: Ordering);
+
+    /// See `AtomicU8::compare_exchange`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the actual value if it wasn't `current`.
+    fn compare_exchange(
+        atomic: &Self::Atomic,
+        current: Self,
+        new: Self,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Self, Self>;
+
+    /// See `AtomicU8::fetch_update`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the actual value if `f` returned `None`.
+    fn fetch_update<F: FnMut(Self) -> Option<Self>>(
+        atomic: &Self::Atomic,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        f: F,
+    ) -> Result<Self, Self>;
+}
+
+macro_rules! impl_atomic_unsigned {
+    ($($t:ty => $atomic:ident, $bits:literal);*) => {
+        $(
+            #[cfg(target_has_atomic = $bits)]
+            impl AtomicUnsigned for $t {
+                type Atomic = std::sync::atomic::$atomic;
+
+                fn new_atomic(value: Self) -> Self::Atomic {
+                    Self::Atomic::new(value)
+                }
+
+                fn load(atomic: &Self::Atomic, order: Ordering) -> Self {
+                    atomic.load(order)
+                }
+
+                fn store(atomic: &Self::Atomic, value: Self, order: Ordering) {
+                    atomic.store(value, order);
+                }
+
+                fn compare_exchange(
+                    atomic: &Self::Atomic,
+                    current: Self,
+                    new: Self,
+                    success: Ordering,
+                    failure: Ordering,
+                ) -> Result<Self, Self> {
+                    atomic.compare_exchange(current, new, success, failure)
+                }
+
+                fn fetch_update<F: FnMut(Self) -> Option<Self>>(
+                    atomic: &Self::Atomic,
+                    set_order: Ordering,
+                    fetch_order: Ordering,
+                    f: F,
+                ) -> Result<Self, Self> {
+                    atomic.fetch_update(set_order, fetch_order, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_unsigned!(
+    u8 => AtomicU8, "8";
+    u16 => AtomicU16, "16";
+    u32 => AtomicU32, "32";
+    u64 => AtomicU64, "64";
+    usize => AtomicUsize, "ptr"
+);
+
+/// `AtomicBint`: A bounded integer that can be shared between threads.
+///
+/// Like [`BintCell`](crate::BintCell), but backed by an atomic integer, so every operation is
+/// lock-free and takes an `Ordering` with the same meaning as on the standard atomics.
+///
+/// Usage:
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use bint::AtomicBint;
+///
+/// let dealer: Arc<AtomicBint> = Arc::new(AtomicBint::new(6));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let dealer = Arc::clone(&dealer);
+///         thread::spawn(move || {
+///             for _ in 0..100 {
+///                 dealer.up(Ordering::Relaxed);
+///             }
+///         })
+///     })
+///     .collect();
+/// for handle in handles {
+///     handle.join().unwrap();
+/// }
+///
+/// // 400 steps around 6 seats.
+/// assert_eq!(4, dealer.load(Ordering::Relaxed));
+/// ```
+#[derive(Debug)]
+pub struct AtomicBint<T: AtomicUnsigned = u8> {
+    value: T::Atomic,
+    boundary: T,
+}
+
+impl<T: AtomicUnsigned> AtomicBint<T> {
+    #[must_use]
+    pub fn new(boundary: T) -> AtomicBint<T> {
+        AtomicBint::from(Bint::new(boundary))
+    }
+
+    /// A value that's out of range is set to 0, as with [`Bint::new_with_value`].
+    #[must_use]
+    pub fn new_with_value(boundary: T, value: T) -> AtomicBint<T> {
+        AtomicBint::from(Bint::new_with_value(boundary, value))
+    }
+
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the boundary is 0, and
+    /// `BintError::ValueOutOfRange` if the value isn't below the boundary.
+    pub fn try_new(boundary: T, value: T) -> Result<AtomicBint<T>, BintError> {
+        Bint::try_new(boundary, value).map(AtomicBint::from)
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.boundary
+    }
+
+    #[must_use]
+    pub fn load(&self, order: Ordering) -> T {
+        T::load(&self.value, order)
+    }
+
+    /// Loads the value along with the boundary.
+    #[must_use]
+    pub fn load_bint(&self, order: Ordering) -> Bint<T> {
+        Bint {
+            value: self.load(order),
+            boundary: self.boundary,
+        }
+    }
+
+    /// Like [`BintCell::set`](crate::BintCell::set), a value that's out of range stores 0.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new(6);
+    ///
+    /// b.store(4, Ordering::SeqCst);
+    /// assert_eq!(4, b.load(Ordering::SeqCst));
+    ///
+    /// b.store(6, Ordering::SeqCst);
+    /// assert_eq!(0, b.load(Ordering::SeqCst));
+    /// ```
+    pub fn store(&self, value: T, order: Ordering) {
+        T::store(&self.value, self.normalize(value), order);
+    }
+
+    /// Moves up one spot and returns the new value.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new_with_value(6, 5);
+    ///
+    /// assert_eq!(0, b.up(Ordering::SeqCst));
+    /// assert_eq!(1, b.up(Ordering::SeqCst));
+    /// ```
+    pub fn up(&self, order: Ordering) -> T {
+        self.update(order, |bint| bint.up())
+    }
+
+    /// Moves down one spot and returns the new value.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new(6);
+    ///
+    /// assert_eq!(5, b.down(Ordering::SeqCst));
+    /// ```
+    pub fn down(&self, order: Ordering) -> T {
+        self.update(order, |bint| bint.down())
+    }
+
+    /// Moves up x spots and returns the new value.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new(6);
+    ///
+    /// assert_eq!(2, b.up_x(20, Ordering::SeqCst));
+    /// ```
+    pub fn up_x(&self, x: u64, order: Ordering) -> T {
+        self.update(order, |bint| bint.up_x(x))
+    }
+
+    /// Moves down x spots and returns the new value.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new(6);
+    ///
+    /// assert_eq!(4, b.down_x(20, Ordering::SeqCst));
+    /// ```
+    pub fn down_x(&self, x: u64, order: Ordering) -> T {
+        self.update(order, |bint| bint.down_x(x))
+    }
+
+    /// Moves up one spot and returns the previous value.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new_with_value(6, 5);
+    ///
+    /// assert_eq!(5, b.fetch_up(Ordering::SeqCst));
+    /// assert_eq!(0, b.load(Ordering::SeqCst));
+    /// ```
+    pub fn fetch_up(&self, order: Ordering) -> T {
+        self.fetch(order, |bint| bint.up())
+    }
+
+    /// Moves down one spot and returns the previous value.
+    pub fn fetch_down(&self, order: Ordering) -> T {
+        self.fetch(order, |bint| bint.down())
+    }
+
+    /// Stores `new` if the value is `current`, as with `AtomicU8::compare_exchange`. A `new`
+    /// value that's out of range stores 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns the actual value if it wasn't `current`.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicBint;
+    ///
+    /// let b: AtomicBint = AtomicBint::new_with_value(6, 3);
+    ///
+    /// assert_eq!(Err(3), b.compare_exchange(2, 4, Ordering::SeqCst, Ordering::SeqCst));
+    /// assert_eq!(Ok(3), b.compare_exchange(3, 4, Ordering::SeqCst, Ordering::SeqCst));
+    /// assert_eq!(4, b.load(Ordering::SeqCst));
+    /// ```
+    pub fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        T::compare_exchange(&self.value, current, self.normalize(new), success, failure)
+    }
+
+    /// Consumes the atomic, returning the value along with the boundary.
+    #[must_use]
+    pub fn into_bint(self) -> Bint<T> {
+        self.load_bint(Ordering::Relaxed)
+    }
+
+    fn normalize(&self, value: T) -> T {
+        Bint::new_with_value(self.boundary, value).value
+    }
+
+    /// Applies the step atomically, returning the new value.
+    fn update<F: Fn(Bint<T>) -> Bint<T>>(&self, order: Ordering, step: F) -> T {
+        let previous = self.fetch(order, &step);
+        step(Bint {
+            value: previous,
+            boundary: self.boundary,
+        })
+        .value
+    }
+
+    /// Applies the step atomically, returning the previous value.
+    fn fetch<F: Fn(Bint<T>) -> Bint<T>>(&self, order: Ordering, step: F) -> T {
+        let result = T::fetch_update(&self.value, order, fetch_ordering(order), |value| {
+            Some(
+                step(Bint {
+                    value,
+                    boundary: self.boundary,
+                })
+                .value,
+            )
+        });
+        // The closure never gives up, so the update always succeeds.
+        match result {
+            Ok(value) | Err(value) => value,
+        }
+    }
+}
+
+impl<T: AtomicUnsigned> Default for AtomicBint<T> {
+    /// Like [`Bint::default`], the boundary is the integer type's maximum.
+    fn default() -> AtomicBint<T> {
+        AtomicBint::from(Bint::default())
+    }
+}
+
+impl<T: AtomicUnsigned> fmt::Display for AtomicBint<T> {
+    /// Writes a relaxed load of the value.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.load(Ordering::Relaxed))
+    }
+}
+
+impl<T: AtomicUnsigned> From<Bint<T>> for AtomicBint<T> {
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::{AtomicBint, Bint};
+    ///
+    /// let b: Bint = Bint::new_with_value(6, 4);
+    /// let atomic = AtomicBint::from(b);
+    ///
+    /// assert_eq!(4, atomic.load(Ordering::SeqCst));
+    /// assert_eq!(b, Bint::from(atomic));
+    /// ```
+    fn from(bint: Bint<T>) -> AtomicBint<T> {
+        AtomicBint {
+            value: T::new_atomic(bint.value),
+            boundary: bint.boundary,
+        }
+    }
+}
+
+impl<T: AtomicUnsigned> From<AtomicBint<T>> for Bint<T> {
+    fn from(atomic: AtomicBint<T>) -> Bint<T> {
+        atomic.into_bint()
+    }
+}
+
+/// `AtomicDrainableBint`: A [`DrainableBintCell`](crate::DrainableBintCell) that can be shared
+/// between threads.
+///
+/// The value and the remaining capacity are packed into a single `AtomicU64`, so draining
+/// capacity and moving the value happen as one atomic operation. However many threads race
+/// for it, exactly `capacity` single steps succeed. Because of the packing, the value is at
+/// most a `u32` and so is the capacity. It's only available on targets with 64-bit atomics.
+///
+/// Usage:
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+///
+/// use bint::AtomicDrainableBint;
+///
+/// let b: AtomicDrainableBint = AtomicDrainableBint::new(4, 2);
+///
+/// assert_eq!(Some(1), b.up(Ordering::SeqCst));
+/// assert_eq!(Some(0), b.down(Ordering::SeqCst));
+/// assert_eq!(None, b.up(Ordering::SeqCst));
+/// assert!(!b.has_capacity(Ordering::SeqCst));
+/// ```
+#[cfg(target_has_atomic = "64")]
+pub struct AtomicDrainableBint<T: Unsigned + Into<u32> = u8> {
+    packed: AtomicU64,
+    boundary: T,
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<T: Unsigned + Into<u32>> AtomicDrainableBint<T> {
+    #[must_use]
+    pub fn new(boundary: T, capacity: u32) -> AtomicDrainableBint<T> {
+        AtomicDrainableBint::new_with_value(boundary, capacity, T::ZERO)
+    }
+
+    /// A value that's out of range is set to 0, as with [`Bint::new_with_value`].
+    #[must_use]
+    pub fn new_with_value(boundary: T, capacity: u32, value: T) -> AtomicDrainableBint<T> {
+        AtomicDrainableBint {
+            packed: AtomicU64::new(pack(Bint::new_with_value(boundary, value).value, capacity)),
+            boundary,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the boundary is 0, and
+    /// `BintError::ValueOutOfRange` if the value isn't below the boundary.
+    pub fn try_new(
+        boundary: T,
+        capacity: u32,
+        value: T,
+    ) -> Result<AtomicDrainableBint<T>, BintError> {
+        let bint = Bint::try_new(boundary, value)?;
+        Ok(AtomicDrainableBint::new_with_value(
+            boundary, capacity, bint.value,
+        ))
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.boundary
+    }
+
+    #[must_use]
+    pub fn load(&self, order: Ordering) -> T {
+        unpack(self.packed.load(order)).0
+    }
+
+    /// The capacity left.
+    #[must_use]
+    pub fn capacity(&self, order: Ordering) -> u32 {
+        unpack::<T>(self.packed.load(order)).1
+    }
+
+    #[must_use]
+    pub fn has_capacity(&self, order: Ordering) -> bool {
+        self.capacity(order) > 0
+    }
+
+    /// Moves up one spot, or returns `None` if the capacity is exhausted.
+    pub fn up(&self, order: Ordering) -> Option<T> {
+        self.up_x(1, order)
+    }
+
+    /// Moves down one spot, or returns `None` if the capacity is exhausted.
+    pub fn down(&self, order: Ordering) -> Option<T> {
+        self.down_x(1, order)
+    }
+
+    /// Moves up x spots. Like [`DrainableBintCell::up_x`](crate::DrainableBintCell::up_x),
+    /// when there isn't enough capacity left it moves up as far as the capacity allows before
+    /// returning `None`.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicDrainableBint;
+    ///
+    /// let b: AtomicDrainableBint = AtomicDrainableBint::new(6, 4);
+    ///
+    /// assert_eq!(Some(3), b.up_x(3, Ordering::SeqCst));
+    /// assert_eq!(None, b.up_x(3, Ordering::SeqCst));
+    /// assert_eq!(4, b.load(Ordering::SeqCst));
+    /// ```
+    pub fn up_x(&self, x: u64, order: Ordering) -> Option<T> {
+        self.drain_step(x, order, Bint::up_x)
+    }
+
+    /// Moves down x spots. When there isn't enough capacity left it moves down as far as the
+    /// capacity allows before returning `None`.
+    ///
+    /// ```
+    /// use std::sync::atomic::Ordering;
+    ///
+    /// use bint::AtomicDrainableBint;
+    ///
+    /// let b: AtomicDrainableBint = AtomicDrainableBint::new(6, 4);
+    ///
+    /// assert_eq!(None, b.down_x(5, Ordering::SeqCst));
+    /// assert_eq!(2, b.load(Ordering::SeqCst));
+    /// ```
+    pub fn down_x(&self, x: u64, order: Ordering) -> Option<T> {
+        self.drain_step(x, order, Bint::down_x)
+    }
+
+    /// Removes one from the capacity without moving, returning the capacity left, or `None`
+    /// if it was already exhausted.
+    pub fn drain(&self, order: Ordering) -> Option<u32> {
+        let previous = self
+            .packed
+            .fetch_update(order, fetch_ordering(order), |packed| {
+                let (value, capacity) = unpack::<T>(packed);
+                Some(pack(value, capacity.checked_sub(1)?))
+            })
+            .ok()?;
+        Some(unpack::<T>(previous).1 - 1)
+    }
+
+    /// Drains up to x from the capacity and moves that far in one atomic update.
+    fn drain_step<F: Fn(Bint<T>, u64) -> Bint<T>>(
+        &self,
+        x: u64,
+        order: Ordering,
+        step: F,
+    ) -> Option<T> {
+        let mut drained = 0;
+        let result = self
+            .packed
+            .fetch_update(order, fetch_ordering(order), |packed| {
+                let (value, capacity) = unpack::<T>(packed);
+                let taken = u32::try_from(x).map_or(capacity, |x| x.min(capacity));
+                drained = u64::from(taken);
+                if drained == 0 {
+                    return None;
+                }
+                let bint = step(Bint::new_unchecked(self.boundary, value), drained);
+                Some(pack(bint.value, capacity - taken))
+            });
+        let value = match result {
+            Ok(previous) => {
+                step(
+                    Bint::new_unchecked(self.boundary, unpack(previous).0),
+                    drained,
+                )
+                .value
+            }
+            Err(current) => unpack(current).0,
+        };
+        if drained == x {
+            Some(value)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<T: Unsigned + Into<u32>> fmt::Debug for AtomicDrainableBint<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (value, capacity) = unpack::<T>(self.packed.load(Ordering::Relaxed));
+        f.debug_struct("AtomicDrainableBint")
+            .field("value", &value)
+            .field("boundary", &self.boundary)
+            .field("capacity", &capacity)
+            .finish()
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<T: Unsigned + Into<u32>> fmt::Display for AtomicDrainableBint<T> {
+    /// Writes a relaxed load of the value.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl<T: Unsigned + Into<u32>> From<AtomicDrainableBint<T>> for Bint<T> {
+    fn from(atomic: AtomicDrainableBint<T>) -> Bint<T> {
+        Bint::new_unchecked(atomic.boundary, atomic.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+/// Packs the capacity into the high half of a `u64` and the value into the low half.
+fn pack<T: Into<u32>>(value: T, capacity: u32) -> u64 {
+    u64::from(capacity) << 32 | u64::from(value.into())
+}
+
+#[cfg(target_has_atomic = "64")]
+#[allow(clippy::cast_possible_truncation)]
+fn unpack<T: Unsigned>(packed: u64) -> (T, u32) {
+    (
+        T::from_u128(u128::from(packed as u32)),
+        (packed >> 32) as u32,
+    )
+}
+
+/// The strongest ordering allowed for the load half of a read-modify-write with `order`.
+fn fetch_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn matches_bint() {
+        let atomic: AtomicBint<u16> = AtomicBint::new_with_value(1_000, 999);
+        let mut bint = atomic.load_bint(Ordering::SeqCst);
+
+        for x in 0..50 {
+            bint = bint.up_x(x * 37);
+            assert_eq!(bint.value(), atomic.up_x(x * 37, Ordering::SeqCst));
+            bint = bint.down();
+            assert_eq!(bint.value(), atomic.down(Ordering::SeqCst));
+        }
+    }
+
+    #[test]
+    fn zero_boundary() {
+        let atomic: AtomicBint = AtomicBint::new(0);
+
+        assert_eq!(0, atomic.up(Ordering::SeqCst));
+        assert_eq!(0, atomic.down_x(7, Ordering::SeqCst));
+        assert_eq!(0, atomic.fetch_down(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn contended_fetch_up() {
+        let atomic: Arc<AtomicBint<usize>> = Arc::new(AtomicBint::new(7));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let atomic = Arc::clone(&atomic);
+                thread::spawn(move || {
+                    let mut seen = [0_usize; 7];
+                    for _ in 0..700 {
+                        seen[atomic.fetch_up(Ordering::AcqRel)] += 1;
+                    }
+                    seen
+                })
+            })
+            .collect();
+        let mut seen = [0_usize; 7];
+        for handle in handles {
+            for (total, count) in seen.iter_mut().zip(handle.join().unwrap().iter()) {
+                *total += count;
+            }
+        }
+
+        assert_eq!([800; 7], seen);
+        assert_eq!(0, atomic.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "64")]
+    fn drainable_matches_cell() {
+        let atomic: AtomicDrainableBint<u16> = AtomicDrainableBint::new_with_value(1_000, 500, 3);
+        let cell = crate::DrainableBintCell::new_with_value(1_000, 500, 3);
+
+        for x in 0..40 {
+            assert_eq!(cell.up_x(x), atomic.up_x(x, Ordering::SeqCst));
+            assert_eq!(cell.down_x(x / 2), atomic.down_x(x / 2, Ordering::SeqCst));
+            assert_eq!(cell.value(), atomic.load(Ordering::SeqCst));
+        }
+        assert_eq!(0, atomic.capacity(Ordering::SeqCst));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "64")]
+    fn drainable_packing() {
+        let atomic: AtomicDrainableBint<u32> =
+            AtomicDrainableBint::new_with_value(u32::MAX, u32::MAX, u32::MAX - 1);
+
+        assert_eq!(Some(0), atomic.up(Ordering::SeqCst));
+        assert_eq!(Some(u32::MAX - 1), atomic.down(Ordering::SeqCst));
+        assert_eq!(Some(u32::MAX - 3), atomic.drain(Ordering::SeqCst));
+        assert_eq!(u32::MAX - 1, atomic.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    #[cfg(target_has_atomic = "64")]
+    fn contended_drain() {
+        let atomic: Arc<AtomicDrainableBint> = Arc::new(AtomicDrainableBint::new(7, 10_000));
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let atomic = Arc::clone(&atomic);
+                thread::spawn(move || {
+                    let mut steps = 0;
+                    while atomic.up(Ordering::AcqRel).is_some() {
+                        steps += 1;
+                    }
+                    steps
+                })
+            })
+            .collect();
+        let steps: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+
+        assert_eq!(10_000, steps);
+        assert_eq!(10_000 % 7, u32::from(atomic.load(Ordering::SeqCst)));
+        assert_eq!(None, atomic.drain(Ordering::SeqCst));
+    }
+}
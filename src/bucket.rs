@@ -0,0 +1,340 @@
+//! A [`DrainableBintCell`] whose capacity regenerates over time, like a token bucket.
+//!
+//! Time comes from a [`Clock`], so tests can drive it by hand with a [`ManualClock`] instead
+//! of waiting on the [`MonotonicClock`].
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use bint::bucket::{ManualClock, ReplenishingBintCell};
+//!
+//! let clock = ManualClock::new();
+//! let b: ReplenishingBintCell<u8, &ManualClock> =
+//!     ReplenishingBintCell::new(6, 2, Duration::from_secs(1), &clock);
+//!
+//! assert_eq!(Some(1), b.up());
+//! assert_eq!(Some(2), b.up());
+//! assert_eq!(None, b.up());
+//!
+//! clock.advance(Duration::from_millis(1_500));
+//! assert_eq!(Some(3), b.up());
+//! assert_eq!(None, b.up());
+//!
+//! clock.advance(Duration::from_millis(500));
+//! assert_eq!(Some(4), b.up());
+//! ```
+
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::{DrainableBintCell, Unsigned};
+
+/// A source of monotonic time, measured from a fixed but arbitrary starting point.
+pub trait Clock {
+    /// The time since the clock's starting point. It must never go backwards.
+    fn now(&self) -> Duration;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// The system's monotonic clock, measured from when it was created.
+#[derive(Clone, Copy, Debug)]
+pub struct MonotonicClock {
+    start: Instant,
+}
+
+impl MonotonicClock {
+    #[must_use]
+    pub fn new() -> MonotonicClock {
+        MonotonicClock {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> MonotonicClock {
+        MonotonicClock::new()
+    }
+}
+
+impl Clock for MonotonicClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// A clock that only moves when told to, for tests and simulations.
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bint::bucket::{Clock, ManualClock};
+///
+/// let clock = ManualClock::new();
+/// clock.advance(Duration::from_secs(3));
+///
+/// assert_eq!(Duration::from_secs(3), clock.now());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ManualClock {
+    now: Cell<Duration>,
+}
+
+impl ManualClock {
+    /// Creates a `ManualClock` at its starting point.
+    #[must_use]
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, by: Duration) {
+        self.now.set(self.now.get() + by);
+    }
+
+    /// Moves the clock to a time, which must not be before its current time.
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+/// `ReplenishingBintCell`: A [`DrainableBintCell`] that gets one step of capacity back every
+/// `refill_every`, up to the capacity it started with.
+///
+/// Each step takes one unit of capacity, so `up` and `down` only succeed while there is some
+/// available. Time spent at full capacity doesn't count towards the next refill.
+///
+/// Usage:
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bint::bucket::ReplenishingBintCell;
+///
+/// let b: ReplenishingBintCell = ReplenishingBintCell::with_monotonic_clock(
+///     6,
+///     3,
+///     Duration::from_secs(60),
+/// );
+///
+/// assert_eq!(Some(3), b.up_x(3));
+/// assert_eq!(None, b.down());
+/// ```
+#[derive(Debug)]
+pub struct ReplenishingBintCell<T: Unsigned = u8, C: Clock = MonotonicClock> {
+    drainable: DrainableBintCell<T>,
+    refill_every: Duration,
+    last_refill: Cell<Duration>,
+    clock: C,
+}
+
+impl<T: Unsigned, C: Clock> ReplenishingBintCell<T, C> {
+    /// Creates a `ReplenishingBintCell` at full capacity. A zero `refill_every` refills it
+    /// completely before every step.
+    #[must_use]
+    pub fn new(
+        boundary: T,
+        capacity: usize,
+        refill_every: Duration,
+        clock: C,
+    ) -> ReplenishingBintCell<T, C> {
+        ReplenishingBintCell {
+            drainable: DrainableBintCell::new_with_max_capacity(boundary, capacity, capacity),
+            refill_every,
+            last_refill: Cell::new(clock.now()),
+            clock,
+        }
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.drainable.boundary()
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.drainable.value()
+    }
+
+    /// The most capacity it can hold, which it starts with.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.drainable.original_capacity()
+    }
+
+    #[must_use]
+    pub fn refill_every(&self) -> Duration {
+        self.refill_every
+    }
+
+    #[must_use]
+    pub fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// The capacity available now, after refilling for the time that has passed.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.replenish();
+        self.drainable.remaining()
+    }
+
+    /// How long until the next step of capacity comes back, or `None` when it's full.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use bint::bucket::{ManualClock, ReplenishingBintCell};
+    ///
+    /// let clock = ManualClock::new();
+    /// let b: ReplenishingBintCell<u8, &ManualClock> =
+    ///     ReplenishingBintCell::new(6, 1, Duration::from_secs(10), &clock);
+    ///
+    /// assert_eq!(None, b.next_refill());
+    ///
+    /// b.up().unwrap();
+    /// clock.advance(Duration::from_secs(4));
+    /// assert_eq!(Some(Duration::from_secs(6)), b.next_refill());
+    /// ```
+    #[must_use]
+    pub fn next_refill(&self) -> Option<Duration> {
+        if self.remaining() == self.capacity() {
+            return None;
+        }
+        let elapsed = self.clock.now().saturating_sub(self.last_refill.get());
+        Some(self.refill_every.saturating_sub(elapsed))
+    }
+
+    #[must_use]
+    pub fn up(&self) -> Option<T> {
+        self.replenish();
+        self.drainable.up()
+    }
+
+    #[must_use]
+    pub fn down(&self) -> Option<T> {
+        self.replenish();
+        self.drainable.down()
+    }
+
+    /// Moves up x spots, each taking one unit of capacity. Like
+    /// [`DrainableBintCell::up_x`], when there isn't enough capacity it moves as far as it can
+    /// and returns none.
+    #[must_use]
+    pub fn up_x(&self, x: u64) -> Option<T> {
+        self.replenish();
+        self.drainable.up_x(x)
+    }
+
+    /// Moves down x spots. See [`ReplenishingBintCell::up_x`].
+    #[must_use]
+    pub fn down_x(&self, x: u64) -> Option<T> {
+        self.replenish();
+        self.drainable.down_x(x)
+    }
+
+    /// Credits the capacity earned since the last refill, carrying the time towards the next
+    /// one over.
+    fn replenish(&self) {
+        let now = self.clock.now();
+        if self.drainable.remaining() == self.capacity() {
+            self.last_refill.set(now);
+            return;
+        }
+        let elapsed = now.saturating_sub(self.last_refill.get());
+        let (elapsed_nanos, every) = (elapsed.as_nanos(), self.refill_every.as_nanos());
+        let earned = elapsed_nanos
+            .checked_div(every)
+            .map_or(usize::MAX, |earned| {
+                usize::try_from(earned).unwrap_or(usize::MAX)
+            });
+        let carried = elapsed_nanos.checked_rem(every).unwrap_or(0);
+        if earned == 0 {
+            return;
+        }
+        if self.drainable.refill(earned) == self.capacity() {
+            self.last_refill.set(now);
+        } else {
+            let carried = u64::try_from(carried).map_or(elapsed, Duration::from_nanos);
+            self.last_refill.set(now.saturating_sub(carried));
+        }
+    }
+}
+
+impl<T: Unsigned> ReplenishingBintCell<T, MonotonicClock> {
+    /// Creates a `ReplenishingBintCell` driven by the system's monotonic clock.
+    #[must_use]
+    pub fn with_monotonic_clock(
+        boundary: T,
+        capacity: usize,
+        refill_every: Duration,
+    ) -> ReplenishingBintCell<T, MonotonicClock> {
+        ReplenishingBintCell::new(boundary, capacity, refill_every, MonotonicClock::new())
+    }
+}
+
+impl<T: Unsigned, C: Clock> fmt::Display for ReplenishingBintCell<T, C> {
+    /// Writes the value.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refills_at_rate() {
+        let clock = ManualClock::new();
+        let b: ReplenishingBintCell<u8, &ManualClock> =
+            ReplenishingBintCell::new(10, 4, Duration::from_millis(100), &clock);
+
+        assert_eq!(None, b.up_x(5));
+        assert_eq!(4, b.value());
+        assert_eq!(0, b.remaining());
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(2, b.remaining());
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(3, b.remaining());
+
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(4, b.remaining());
+        assert_eq!(None, b.next_refill());
+
+        // Time spent full isn't banked.
+        clock.advance(Duration::from_millis(50));
+        assert_eq!(Some(8), b.up_x(4));
+        clock.advance(Duration::from_millis(99));
+        assert_eq!(None, b.down());
+        clock.advance(Duration::from_millis(1));
+        assert_eq!(Some(7), b.down());
+    }
+
+    #[test]
+    fn zero_interval_is_always_full() {
+        let clock = ManualClock::new();
+        let b: ReplenishingBintCell<u8, &ManualClock> =
+            ReplenishingBintCell::new(3, 2, Duration::ZERO, &clock);
+
+        assert_eq!(Some(2), b.up_x(2));
+        assert_eq!(Some(1), b.up_x(2));
+        assert_eq!(2, b.remaining());
+        assert_eq!(None, b.up_x(3));
+        assert_eq!(2, b.remaining());
+    }
+}
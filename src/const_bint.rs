@@ -0,0 +1,445 @@
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::Bint;
+
+/// `ConstBint`: A bounded integer whose boundary is part of its type.
+///
+/// Behaves like a `Bint<u8>`, but the boundary is a const generic parameter, so it takes up a
+/// single byte and a zero boundary is rejected at compile time.
+///
+/// Usage:
+///
+/// ```
+/// use bint::ConstBint;
+///
+/// let b: ConstBint<6> = ConstBint::new_with_value(5);
+/// let c = b.up();
+/// let d = c.up();
+///
+/// assert_eq!(5, b.value());
+/// assert_eq!(0, c.value());
+/// assert_eq!(1, d.value());
+/// assert_eq!(1, std::mem::size_of::<ConstBint<6>>());
+/// ```
+///
+/// A zero boundary doesn't compile:
+///
+/// ```compile_fail
+/// use bint::ConstBint;
+///
+/// let b: ConstBint<0> = ConstBint::new();
+/// ```
+///
+/// ```compile_fail
+/// use bint::ConstBint;
+///
+/// let b: ConstBint<0> = ConstBint::default();
+/// ```
+///
+/// ```compile_fail
+/// use bint::{Bint, ConstBint};
+/// use std::convert::TryFrom;
+///
+/// let b = ConstBint::<0>::try_from(Bint::new(0));
+/// ```
+///
+/// Every constructor, including `Default`, `TryFrom<Bint>` and those of [`ConstBintCell`],
+/// goes through the check. It's only made when a value is created, though, after generics
+/// are filled in, so `ConstBint<0>` can still be named in a type. No value of it can exist:
+///
+/// ```
+/// use bint::ConstBint;
+///
+/// fn never(b: Option<ConstBint<0>>) -> bool {
+///     b.is_some()
+/// }
+///
+/// assert!(!never(None));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstBint<const N: u8> {
+    value: u8,
+}
+
+impl<const N: u8> ConstBint<N> {
+    // Indexes out of bounds, and so fails to compile, when the boundary is zero. Only
+    // evaluated where it's used, so new_with_value is the only place that builds a ConstBint,
+    // and every other constructor, from_bint and TryFrom<Bint> included, calls it.
+    #[allow(clippy::cast_lossless)]
+    const NONZERO: () = [()][(N == 0) as usize];
+
+    /// ```
+    /// use bint::ConstBint;
+    ///
+    /// let b: ConstBint<6> = ConstBint::new();
+    ///
+    /// assert_eq!(0, b.value());
+    /// assert_eq!(5, b.down().value());
+    /// ```
+    #[must_use]
+    pub fn new() -> ConstBint<N> {
+        ConstBint::new_with_value(0)
+    }
+
+    /// ```
+    /// use bint::ConstBint;
+    ///
+    /// let b: ConstBint<10> = ConstBint::new_with_value(7);
+    /// assert_eq!(7, b.value());
+    ///
+    /// let b: ConstBint<10> = ConstBint::new_with_value(23);
+    /// assert_eq!(0, b.value());
+    /// ```
+    #[must_use]
+    pub fn new_with_value(value: u8) -> ConstBint<N> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NONZERO;
+        if value >= N {
+            ConstBint { value: 0 }
+        } else {
+            ConstBint { value }
+        }
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> u8 {
+        N
+    }
+
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        self.value
+    }
+
+    /// ```
+    /// use bint::ConstBint;
+    ///
+    /// let b: ConstBint<6> = ConstBint::new_with_value(4);
+    ///
+    /// let b = b.up();
+    /// assert_eq!(5, b.value());
+    ///
+    /// let b = b.up();
+    /// assert_eq!(0, b.value());
+    /// ```
+    #[must_use]
+    pub fn up(self) -> ConstBint<N> {
+        ConstBint::from_bint(Bint::from(self).up())
+    }
+
+    /// ```
+    /// use bint::ConstBint;
+    ///
+    /// let b: ConstBint<6> = ConstBint::new_with_value(4);
+    ///
+    /// assert_eq!(1, b.up_x(3).value());
+    /// ```
+    #[must_use]
+    pub fn up_x(self, x: u64) -> ConstBint<N> {
+        ConstBint::from_bint(Bint::from(self).up_x(x))
+    }
+
+    /// ```
+    /// use bint::ConstBint;
+    ///
+    /// let b: ConstBint<6> = ConstBint::new_with_value(1);
+    ///
+    /// let b = b.down();
+    /// assert_eq!(0, b.value());
+    ///
+    /// let b = b.down();
+    /// assert_eq!(5, b.value());
+    /// ```
+    #[must_use]
+    pub fn down(self) -> ConstBint<N> {
+        ConstBint::from_bint(Bint::from(self).down())
+    }
+
+    /// ```
+    /// use bint::ConstBint;
+    ///
+    /// let b: ConstBint<6> = ConstBint::new_with_value(4);
+    ///
+    /// assert_eq!(4, b.down_x(6).value());
+    /// assert_eq!(1, b.down_x(3).value());
+    /// ```
+    #[must_use]
+    pub fn down_x(self, x: u64) -> ConstBint<N> {
+        ConstBint::from_bint(Bint::from(self).down_x(x))
+    }
+
+    /// Only called with a `Bint` whose boundary is `N`. Goes through `new_with_value` rather
+    /// than building the struct, so that it can't skip the zero boundary check.
+    fn from_bint(bint: Bint) -> ConstBint<N> {
+        ConstBint::new_with_value(bint.value)
+    }
+}
+
+impl<const N: u8> Default for ConstBint<N> {
+    fn default() -> Self {
+        ConstBint::new()
+    }
+}
+
+impl<const N: u8> fmt::Display for ConstBint<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl<const N: u8> From<ConstBint<N>> for Bint {
+    /// ```
+    /// use bint::{Bint, ConstBint};
+    ///
+    /// let b: ConstBint<8> = ConstBint::new_with_value(3);
+    ///
+    /// assert_eq!(Bint::new_with_value(8, 3), Bint::from(b));
+    /// ```
+    fn from(bint: ConstBint<N>) -> Self {
+        Bint {
+            value: bint.value,
+            boundary: N,
+        }
+    }
+}
+
+impl<const N: u8> TryFrom<Bint> for ConstBint<N> {
+    type Error = Bint;
+
+    /// Fails with the original `Bint` when its boundary isn't `N`.
+    ///
+    /// ```
+    /// use bint::{Bint, ConstBint};
+    /// use std::convert::TryFrom;
+    ///
+    /// let b: Bint = Bint::new_with_value(8, 3);
+    ///
+    /// assert_eq!(Ok(ConstBint::new_with_value(3)), ConstBint::<8>::try_from(b));
+    /// assert_eq!(Err(b), ConstBint::<6>::try_from(b));
+    /// ```
+    fn try_from(bint: Bint) -> Result<Self, Self::Error> {
+        if bint.boundary == N {
+            // Goes through new_with_value, so a zero N still fails to compile even though
+            // Bint::new(0) would match it.
+            Ok(ConstBint::new_with_value(bint.value))
+        } else {
+            Err(bint)
+        }
+    }
+}
+
+/// `ConstBintCell`: A [`ConstBint`] captured in a [`Cell`](https://doc.rust-lang.org/std/cell/struct.Cell.html).
+///
+/// Usage:
+///
+/// ```
+/// use bint::ConstBintCell;
+///
+/// let b: ConstBintCell<6> = ConstBintCell::new();
+///
+/// b.down();
+/// assert_eq!(5, b.value());
+///
+/// b.up();
+/// b.up();
+/// b.up();
+/// assert_eq!(2, b.value());
+/// ```
+///
+/// Like [`ConstBint`], a zero boundary doesn't compile:
+///
+/// ```compile_fail
+/// use bint::ConstBintCell;
+///
+/// let b: ConstBintCell<0> = ConstBintCell::default();
+/// ```
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ConstBintCell<const N: u8> {
+    cell: Cell<ConstBint<N>>,
+}
+
+impl<const N: u8> ConstBintCell<N> {
+    #[must_use]
+    pub fn new() -> ConstBintCell<N> {
+        ConstBintCell::from(ConstBint::new())
+    }
+
+    /// ```
+    /// use bint::ConstBintCell;
+    ///
+    /// let b: ConstBintCell<6> = ConstBintCell::new_with_value(6);
+    /// assert_eq!(0, b.value());
+    ///
+    /// let b: ConstBintCell<6> = ConstBintCell::new_with_value(3);
+    /// assert_eq!(3, b.value());
+    /// ```
+    #[must_use]
+    pub fn new_with_value(value: u8) -> ConstBintCell<N> {
+        ConstBintCell::from(ConstBint::new_with_value(value))
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> u8 {
+        N
+    }
+
+    #[must_use]
+    pub fn value(&self) -> u8 {
+        self.cell.get().value()
+    }
+
+    /// ```
+    /// use bint::ConstBintCell;
+    ///
+    /// let b: ConstBintCell<6> = ConstBintCell::new();
+    ///
+    /// b.up();
+    /// assert_eq!(2, b.up());
+    /// ```
+    pub fn up(&self) -> u8 {
+        self.replace(self.cell.get().up())
+    }
+
+    /// ```
+    /// use bint::ConstBintCell;
+    ///
+    /// let b: ConstBintCell<6> = ConstBintCell::new();
+    ///
+    /// assert_eq!(3, b.up_x(9));
+    /// ```
+    pub fn up_x(&self, x: u64) -> u8 {
+        self.replace(self.cell.get().up_x(x))
+    }
+
+    /// ```
+    /// use bint::ConstBintCell;
+    ///
+    /// let b: ConstBintCell<6> = ConstBintCell::new();
+    ///
+    /// b.down();
+    /// assert_eq!(4, b.down());
+    /// ```
+    pub fn down(&self) -> u8 {
+        self.replace(self.cell.get().down())
+    }
+
+    /// ```
+    /// use bint::ConstBintCell;
+    ///
+    /// let b: ConstBintCell<6> = ConstBintCell::new();
+    ///
+    /// assert_eq!(4, b.down_x(2));
+    /// ```
+    pub fn down_x(&self, x: u64) -> u8 {
+        self.replace(self.cell.get().down_x(x))
+    }
+
+    pub fn reset(&self) {
+        self.set(0);
+    }
+
+    /// Values outside of the boundary reset the cell to 0.
+    ///
+    /// ```
+    /// use bint::ConstBintCell;
+    ///
+    /// let b: ConstBintCell<8> = ConstBintCell::new();
+    ///
+    /// b.set(5);
+    /// assert_eq!(5, b.value());
+    ///
+    /// b.set(8);
+    /// assert_eq!(0, b.value());
+    /// ```
+    pub fn set(&self, value: u8) {
+        self.cell.set(ConstBint::new_with_value(value));
+    }
+
+    fn replace(&self, bint: ConstBint<N>) -> u8 {
+        self.cell.set(bint);
+        bint.value()
+    }
+}
+
+impl<const N: u8> Default for ConstBintCell<N> {
+    fn default() -> Self {
+        ConstBintCell::new()
+    }
+}
+
+impl<const N: u8> fmt::Display for ConstBintCell<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<const N: u8> From<ConstBint<N>> for ConstBintCell<N> {
+    fn from(bint: ConstBint<N>) -> Self {
+        ConstBintCell {
+            cell: Cell::new(bint),
+        }
+    }
+}
+
+impl<const N: u8> From<ConstBintCell<N>> for ConstBint<N> {
+    fn from(cell: ConstBintCell<N>) -> Self {
+        cell.cell.get()
+    }
+}
+
+impl<const N: u8> From<&ConstBintCell<N>> for ConstBint<N> {
+    fn from(cell: &ConstBintCell<N>) -> Self {
+        cell.cell.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        assert_eq!(1, std::mem::size_of::<ConstBint<52>>());
+        assert_eq!(1, std::mem::size_of::<ConstBintCell<52>>());
+    }
+
+    #[test]
+    fn up_loop() {
+        let mut b: ConstBint<24> = ConstBint::new();
+
+        for _ in 0..48 {
+            b = b.up();
+        }
+
+        assert_eq!(0, b.value());
+    }
+
+    #[test]
+    fn max_boundary() {
+        let b: ConstBint<{ u8::MAX }> = ConstBint::new_with_value(u8::MAX - 1);
+
+        assert_eq!(0, b.up().value());
+        assert_eq!(u8::MAX - 1, b.up().down().value());
+    }
+
+    #[test]
+    fn bint_round_trip() {
+        let b: ConstBint<52> = ConstBint::new_with_value(51);
+
+        assert_eq!(Ok(b), ConstBint::try_from(Bint::from(b)));
+    }
+
+    #[test]
+    fn cell_down_loop() {
+        let b: ConstBintCell<6> = ConstBintCell::new();
+
+        for _ in 0..12 {
+            b.down();
+        }
+
+        assert_eq!(0, b.value());
+        assert_eq!(ConstBint::new(), ConstBint::from(b));
+    }
+}
@@ -0,0 +1,173 @@
+//! Chinese remainder combination of [`Bint`]s.
+//!
+//! Stepping a single `Bint` with a boundary of 30 steps a pair of `Bint`s with boundaries of 6
+//! and 10 along with it. [`combine`] goes the other way, finding the single value that the
+//! pair was derived from.
+//!
+//! ```
+//! use bint::{crt, Bint};
+//!
+//! let mut b: Bint = Bint::new(30);
+//! for _ in 0..30 {
+//!     let pair = [
+//!         Bint::new_with_value(6, b.value() % 6),
+//!         Bint::new_with_value(10, b.value() % 10),
+//!     ];
+//!     assert_eq!(Ok(b), crt::combine(&pair));
+//!     b = b.up();
+//! }
+//! ```
+
+use crate::{modular, Bint, BintError, Unsigned};
+
+/// Combines the `Bint`s into the one `Bint`, with a boundary of the least common multiple of
+/// their boundaries, whose value leaves each of their values as the remainder.
+///
+/// # Errors
+///
+/// Returns `BintError::ZeroBoundary` if any of the boundaries is 0, `BintError::Inconsistent`
+/// if no value leaves all of the remainders, which can only happen when boundaries share a
+/// factor, and `BintError::Overflow` if the least common multiple doesn't fit in the integer
+/// type.
+///
+/// ```
+/// use bint::{crt, Bint, BintError};
+///
+/// let residues: [Bint; 3] = [
+///     Bint::new_with_value(3, 2),
+///     Bint::new_with_value(5, 3),
+///     Bint::new_with_value(7, 2),
+/// ];
+/// assert_eq!(Ok(Bint::new_with_value(105, 23)), crt::combine(&residues));
+///
+/// let inconsistent: [Bint; 2] = [Bint::new_with_value(4, 1), Bint::new_with_value(6, 2)];
+/// assert_eq!(Err(BintError::Inconsistent), crt::combine(&inconsistent));
+///
+/// let too_big: [Bint; 2] = [Bint::new(16), Bint::new(17)];
+/// assert_eq!(Err(BintError::Overflow), crt::combine(&too_big));
+/// ```
+pub fn combine<T: Unsigned>(bints: &[Bint<T>]) -> Result<Bint<T>, BintError> {
+    let (value, boundary) =
+        bints
+            .iter()
+            .try_fold((0_u128, 1_u128), |(value, boundary), bint| {
+                combine_pair(
+                    value,
+                    boundary,
+                    bint.value().to_u128(),
+                    bint.boundary().to_u128(),
+                )
+            })?;
+    if boundary > T::MAX.to_u128() {
+        return Err(BintError::Overflow);
+    }
+    Ok(Bint::new_with_value(
+        T::from_u128(boundary),
+        T::from_u128(value),
+    ))
+}
+
+/// The number of steps after which all of the boundaries wrap back to 0 together, which is
+/// their least common multiple. Returns `None` if any of the boundaries is 0, or if the period
+/// doesn't fit in the integer type.
+///
+/// ```
+/// use bint::crt;
+///
+/// assert_eq!(Some(30), crt::period(&[6_u8, 10]));
+/// assert_eq!(Some(60), crt::period(&[3_u8, 4, 5, 6]));
+/// assert_eq!(None, crt::period(&[16_u8, 17]));
+/// assert_eq!(Some(272), crt::period(&[16_u16, 17]));
+/// ```
+#[must_use]
+pub fn period<T: Unsigned>(boundaries: &[T]) -> Option<T> {
+    let period = boundaries.iter().try_fold(1_u128, |period, boundary| {
+        let boundary = boundary.to_u128();
+        if boundary == 0 {
+            return None;
+        }
+        (period / modular::gcd(period, boundary)).checked_mul(boundary)
+    })?;
+    if period > T::MAX.to_u128() {
+        None
+    } else {
+        Some(T::from_u128(period))
+    }
+}
+
+/// Finds x modulo lcm(m1, m2) with x = r1 (mod m1) and x = r2 (mod m2).
+fn combine_pair(r1: u128, m1: u128, r2: u128, m2: u128) -> Result<(u128, u128), BintError> {
+    if m2 == 0 {
+        return Err(BintError::ZeroBoundary);
+    }
+    let g = modular::gcd(m1, m2);
+    let difference = modular::down(r2, r1, m2);
+    if difference % g != 0 {
+        return Err(BintError::Inconsistent);
+    }
+    let m2 = m2 / g;
+    let lcm = m1.checked_mul(m2).ok_or(BintError::Overflow)?;
+    let k = modular::mul(difference / g, modular::inverse(m1 / g, m2), m2);
+    // k < m2, so m1 * k + r1 < lcm.
+    Ok((r1 + m1 * k, lcm))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combine_all() {
+        let boundaries: [u16; 3] = [4, 6, 9];
+
+        for value in 0..36 {
+            let residues: Vec<Bint<u16>> = boundaries
+                .iter()
+                .map(|&boundary| Bint::new_with_value(boundary, value % boundary))
+                .collect();
+            assert_eq!(Ok(Bint::new_with_value(36, value)), combine(&residues));
+        }
+    }
+
+    #[test]
+    fn combine_edges() {
+        assert_eq!(Ok(Bint::new(1)), combine::<u8>(&[]));
+        assert_eq!(
+            Err(BintError::ZeroBoundary),
+            combine::<u8>(&[Bint::new(3), Bint::new(0)])
+        );
+        assert_eq!(
+            Ok(Bint::new_with_value(6, 5)),
+            combine::<u8>(&[Bint::new_with_value(6, 5), Bint::new_with_value(6, 5)])
+        );
+    }
+
+    #[test]
+    fn combine_wide() {
+        let big = u128::MAX - 158;
+        let residues = [
+            Bint::new_with_value(big, big - 1),
+            Bint::new_with_value(1 << 64, 5),
+        ];
+
+        assert_eq!(Err(BintError::Overflow), combine(&residues));
+
+        let residues: [Bint<u128>; 2] = [
+            Bint::new_with_value(u128::from(u64::MAX), 7),
+            Bint::new_with_value(1 << 64, 9),
+        ];
+        let combined = combine(&residues).unwrap();
+
+        assert_eq!(u128::from(u64::MAX) << 64, combined.boundary());
+        assert_eq!(7, combined.value() % u128::from(u64::MAX));
+        assert_eq!(9, combined.value() % (1 << 64));
+    }
+
+    #[test]
+    fn period_edges() {
+        assert_eq!(Some(1), period::<u8>(&[]));
+        assert_eq!(None, period::<u8>(&[4, 0]));
+        assert_eq!(Some(u64::MAX), period(&[u64::MAX, u64::MAX]));
+    }
+}
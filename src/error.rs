@@ -0,0 +1,135 @@
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+/// Why a bounded integer couldn't be created or combined.
+///
+/// Values are widened to `u128` so that the error is the same for every integer type.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BintError {
+    /// The boundary is 0, so there is no valid value.
+    ZeroBoundary,
+    /// The value isn't below the boundary.
+    ValueOutOfRange { value: u128, boundary: u128 },
+    /// The result doesn't fit in the integer type.
+    Overflow,
+    /// The residues can't all hold at once, because boundaries that share a factor disagree
+    /// about it.
+    Inconsistent,
+    /// The range's maximum is below its minimum.
+    EmptyRange { min: u128, max: u128 },
+    /// The value isn't within the inclusive range.
+    OutsideRange { value: u128, min: u128, max: u128 },
+    /// The step would wrap past either end of the range.
+    WouldWrap,
+    /// A drainable cell's capacity is above its maximum capacity.
+    CapacityAboveMax { capacity: usize, max: usize },
+}
+
+impl fmt::Display for BintError {
+    /// ```
+    /// use bint::BintError;
+    ///
+    /// let error = BintError::ValueOutOfRange { value: 7, boundary: 6 };
+    ///
+    /// assert_eq!("value 7 is out of range for boundary 6", error.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BintError::ZeroBoundary => write!(f, "boundary must be greater than 0"),
+            BintError::ValueOutOfRange { value, boundary } => {
+                write!(
+                    f,
+                    "value {} is out of range for boundary {}",
+                    value, boundary
+                )
+            }
+            BintError::Overflow => write!(f, "result doesn't fit in the integer type"),
+            BintError::Inconsistent => write!(f, "residues are inconsistent"),
+            BintError::EmptyRange { min, max } => write!(f, "range {}..={} is empty", min, max),
+            BintError::OutsideRange { value, min, max } => {
+                write!(f, "value {} is outside of range {}..={}", value, min, max)
+            }
+            BintError::WouldWrap => write!(f, "step would wrap around"),
+            BintError::CapacityAboveMax { capacity, max } => {
+                write!(
+                    f,
+                    "capacity {} is above the maximum capacity {}",
+                    capacity, max
+                )
+            }
+        }
+    }
+}
+
+impl Error for BintError {}
+
+/// Why text couldn't be parsed into a bounded integer.
+///
+/// The text format is `value/boundary`, with ` [cap capacity]` after it for a
+/// [`DrainableBintCell`](crate::DrainableBintCell), as written by the alternate `{:#}` form of
+/// `Display`. The capacity part can also carry the original and maximum capacity, as in
+/// ` [cap 2/4 max 8]`.
+///
+/// ```
+/// use bint::{Bint, BintError, ParseBintError};
+///
+/// assert_eq!(Err(ParseBintError::Format), "3".parse::<Bint>());
+/// assert!(matches!("x/6".parse::<Bint>(), Err(ParseBintError::Value(_))));
+/// assert_eq!(
+///     Err(ParseBintError::Invalid(BintError::ValueOutOfRange { value: 7, boundary: 6 })),
+///     "7/6".parse::<Bint>()
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseBintError {
+    /// The text isn't in the expected form.
+    Format,
+    /// The value isn't an integer of the right type.
+    Value(ParseIntError),
+    /// The boundary isn't an integer of the right type.
+    Boundary(ParseIntError),
+    /// The capacity isn't a `usize`.
+    Capacity(ParseIntError),
+    /// The parts parsed, but don't make a valid bounded integer.
+    Invalid(BintError),
+}
+
+impl fmt::Display for ParseBintError {
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let error = "7/6".parse::<Bint>().unwrap_err();
+    ///
+    /// assert_eq!("invalid bint: value 7 is out of range for boundary 6", error.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseBintError::Format => write!(f, "expected value/boundary"),
+            ParseBintError::Value(error) => write!(f, "invalid value: {}", error),
+            ParseBintError::Boundary(error) => write!(f, "invalid boundary: {}", error),
+            ParseBintError::Capacity(error) => write!(f, "invalid capacity: {}", error),
+            ParseBintError::Invalid(error) => write!(f, "invalid bint: {}", error),
+        }
+    }
+}
+
+impl Error for ParseBintError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseBintError::Format => None,
+            ParseBintError::Value(error)
+            | ParseBintError::Boundary(error)
+            | ParseBintError::Capacity(error) => Some(error),
+            ParseBintError::Invalid(error) => Some(error),
+        }
+    }
+}
+
+impl From<BintError> for ParseBintError {
+    fn from(error: BintError) -> ParseBintError {
+        ParseBintError::Invalid(error)
+    }
+}
@@ -0,0 +1,1054 @@
+//! Events fired when an observed cell changes value, wraps around or runs out of capacity.
+//!
+//! [`ObservedBintCell`] and [`ObservedDrainableBintCell`] wrap a [`BintCell`] and a
+//! [`DrainableBintCell`], calling every registered [`BintObserver`] as they move, so that
+//! lap-driven logic doesn't have to diff values after each step. Any `FnMut(BintEvent<T>)`
+//! closure is an observer.
+//!
+//! Observation requires these wrapper types. The plain `BintCell` and `DrainableBintCell`
+//! have no hooks, which keeps them `Clone`, `Eq` and `Ord` and as small as before. Only steps
+//! taken through the wrapper fire events, so convert a cell with `From` and move it through
+//! the wrapper from then on, and use `into_inner` to get it back. The wrappers forward every
+//! method of the cell that moves it, including the carry, `_until`, `_at` and policy ones.
+//!
+//! An observer may step or set the cell it observes, or register more observers on it. The
+//! events that fires are queued until every observer has seen the current one, so all of them
+//! see the events in the same order, and a step still returns where that step left the cell.
+//! Observers registered during an event first hear about the next one. If an observer panics,
+//! every observer stays registered and the queued events are dropped.
+//!
+//! ```
+//! use std::cell::Cell;
+//! use std::rc::Rc;
+//!
+//! use bint::event::{BintEvent, ObservedBintCell};
+//! use bint::BintCell;
+//!
+//! let orbits = Rc::new(Cell::new(0));
+//! let button: ObservedBintCell = ObservedBintCell::from(BintCell::new(6));
+//!
+//! let counter = Rc::clone(&orbits);
+//! button.add_observer(move |event| {
+//!     if let BintEvent::WrappedForward { laps } = event {
+//!         counter.set(counter.get() + laps);
+//!     }
+//! });
+//!
+//! button.up_x(13);
+//! assert_eq!(1, button.value());
+//! assert_eq!(2, orbits.get());
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::{fmt, mem};
+
+use crate::policy::OverflowPolicy;
+use crate::{Bint, BintCell, BintError, DrainableBintCell, Unsigned};
+
+/// What happened to an observed cell during a step.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum BintEvent<T> {
+    /// The value changed.
+    Changed { from: T, to: T },
+    /// The value wrapped past the top back to 0, this many times.
+    WrappedForward { laps: u64 },
+    /// The value wrapped below 0 back to the top, this many times.
+    WrappedBackward { laps: u64 },
+    /// The last of the capacity was used up.
+    Exhausted,
+}
+
+/// Receives the events of an observed cell.
+pub trait BintObserver<T> {
+    fn notify(&mut self, event: BintEvent<T>);
+}
+
+impl<T, F: FnMut(BintEvent<T>)> BintObserver<T> for F {
+    fn notify(&mut self, event: BintEvent<T>) {
+        self(event);
+    }
+}
+
+/// The registered observers of a cell, and the events waiting for them.
+struct Observers<T> {
+    list: RefCell<Vec<Box<dyn BintObserver<T>>>>,
+    pending: RefCell<VecDeque<BintEvent<T>>>,
+    notifying: Cell<bool>,
+}
+
+impl<T: Unsigned> Observers<T> {
+    fn new() -> Observers<T> {
+        Observers {
+            list: RefCell::new(Vec::new()),
+            pending: RefCell::new(VecDeque::new()),
+            notifying: Cell::new(false),
+        }
+    }
+
+    fn add(&self, observer: impl BintObserver<T> + 'static) {
+        self.list.borrow_mut().push(Box::new(observer));
+    }
+
+    fn len(&self) -> usize {
+        self.list.borrow().len()
+    }
+
+    /// Fires the event, or queues it if an observer caused it while handling another one.
+    fn notify(&self, event: BintEvent<T>) {
+        self.pending.borrow_mut().push_back(event);
+        if self.notifying.replace(true) {
+            return;
+        }
+        let mut running = Running {
+            observers: self,
+            taken: Vec::new(),
+        };
+        while let Some(event) = self.next_pending() {
+            // The list is taken out while the observers run, so that they can register more
+            // observers on the cell.
+            running.taken = mem::take(&mut *self.list.borrow_mut());
+            for observer in &mut running.taken {
+                observer.notify(event);
+            }
+            running.put_back();
+        }
+    }
+
+    fn next_pending(&self) -> Option<BintEvent<T>> {
+        self.pending.borrow_mut().pop_front()
+    }
+
+    fn changed(&self, from: T, to: T) {
+        if from != to {
+            self.notify(BintEvent::Changed { from, to });
+        }
+    }
+
+    fn forward(&self, from: T, to: T, laps: u64) {
+        self.changed(from, to);
+        if laps > 0 {
+            self.notify(BintEvent::WrappedForward { laps });
+        }
+    }
+
+    fn backward(&self, from: T, to: T, laps: u64) {
+        self.changed(from, to);
+        if laps > 0 {
+            self.notify(BintEvent::WrappedBackward { laps });
+        }
+    }
+}
+
+/// The observers taken out of the list while they run. Dropping it puts them back, even when
+/// an observer panics.
+struct Running<'a, T: Unsigned> {
+    observers: &'a Observers<T>,
+    taken: Vec<Box<dyn BintObserver<T>>>,
+}
+
+impl<T: Unsigned> Running<'_, T> {
+    /// Puts the taken observers back ahead of any registered while they ran.
+    fn put_back(&mut self) {
+        let mut current = self.observers.list.borrow_mut();
+        let added = mem::replace(&mut *current, mem::take(&mut self.taken));
+        current.extend(added);
+    }
+}
+
+impl<T: Unsigned> Drop for Running<'_, T> {
+    fn drop(&mut self) {
+        self.put_back();
+        self.observers.pending.borrow_mut().clear();
+        self.observers.notifying.set(false);
+    }
+}
+
+/// `ObservedBintCell`: A [`BintCell`] that fires a [`BintEvent`] to its observers whenever
+/// its value changes or wraps.
+///
+/// Usage:
+///
+/// ```
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// use bint::event::{BintEvent, ObservedBintCell};
+/// use bint::BintCell;
+///
+/// let events = Rc::new(RefCell::new(Vec::new()));
+/// let b: ObservedBintCell = ObservedBintCell::from(BintCell::new_with_value(4, 3));
+///
+/// let log = Rc::clone(&events);
+/// b.add_observer(move |event| log.borrow_mut().push(event));
+/// b.up();
+///
+/// assert_eq!(
+///     vec![
+///         BintEvent::Changed { from: 3, to: 0 },
+///         BintEvent::WrappedForward { laps: 1 },
+///     ],
+///     *events.borrow()
+/// );
+/// ```
+pub struct ObservedBintCell<T: Unsigned = u8> {
+    cell: BintCell<T>,
+    observers: Observers<T>,
+}
+
+impl<T: Unsigned> ObservedBintCell<T> {
+    #[must_use]
+    pub fn new(boundary: T) -> ObservedBintCell<T> {
+        ObservedBintCell::from(BintCell::new(boundary))
+    }
+
+    /// Registers an observer, to be called after every later step.
+    pub fn add_observer(&self, observer: impl BintObserver<T> + 'static) {
+        self.observers.add(observer);
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.cell.boundary()
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.cell.value()
+    }
+
+    pub fn up(&self) -> T {
+        self.up_x(1)
+    }
+
+    pub fn down(&self) -> T {
+        self.down_x(1)
+    }
+
+    pub fn up_x(&self, x: u64) -> T {
+        self.up_with_carry(x).0
+    }
+
+    pub fn down_x(&self, x: u64) -> T {
+        self.down_with_carry(x).0
+    }
+
+    /// Moves up for positive values of x and down for negative ones.
+    pub fn step(&self, x: i64) -> T {
+        if x < 0 {
+            self.down_x(x.unsigned_abs())
+        } else {
+            self.up_x(x.unsigned_abs())
+        }
+    }
+
+    pub fn up_with_carry(&self, x: u64) -> (T, u64) {
+        let from = self.value();
+        let (to, laps) = self.cell.up_with_carry(x);
+        self.observers.forward(from, to, laps);
+        (to, laps)
+    }
+
+    pub fn down_with_carry(&self, x: u64) -> (T, u64) {
+        let from = self.value();
+        let (to, laps) = self.cell.down_with_carry(x);
+        self.observers.backward(from, to, laps);
+        (to, laps)
+    }
+
+    /// Moves up for positive values of x and down for negative ones, also returning the
+    /// number of wraps. Wraps below 0 are counted as negative.
+    pub fn step_with_carry(&self, x: i64) -> (T, i64) {
+        if x < 0 {
+            let (value, laps) = self.down_with_carry(x.unsigned_abs());
+            (value, i64::try_from(laps).map_or(i64::MIN, |laps| -laps))
+        } else {
+            let (value, laps) = self.up_with_carry(x.unsigned_abs());
+            (value, i64::try_from(laps).unwrap_or(i64::MAX))
+        }
+    }
+
+    /// Moves up to the next value the predicate accepts, like [`BintCell::up_until`]. Landing
+    /// on a value that isn't above the current one, including the current one itself, fires a
+    /// wrap.
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use bint::event::{BintEvent, ObservedBintCell};
+    ///
+    /// let events = Rc::new(RefCell::new(Vec::new()));
+    /// let seat: ObservedBintCell = ObservedBintCell::new(4);
+    /// seat.set(2);
+    ///
+    /// let log = Rc::clone(&events);
+    /// seat.add_observer(move |event| log.borrow_mut().push(event));
+    ///
+    /// assert_eq!(Some(1), seat.up_until(|s| s == 1));
+    /// assert_eq!(
+    ///     vec![
+    ///         BintEvent::Changed { from: 2, to: 1 },
+    ///         BintEvent::WrappedForward { laps: 1 },
+    ///     ],
+    ///     *events.borrow()
+    /// );
+    /// ```
+    pub fn up_until<P: FnMut(T) -> bool>(&self, predicate: P) -> Option<T> {
+        let from = self.value();
+        let to = self.cell.up_until(predicate)?;
+        self.observers.forward(from, to, u64::from(to <= from));
+        Some(to)
+    }
+
+    /// Moves down to the next value the predicate accepts, like [`BintCell::down_until`].
+    /// Landing on a value that isn't below the current one, including the current one itself,
+    /// fires a wrap.
+    pub fn down_until<P: FnMut(T) -> bool>(&self, predicate: P) -> Option<T> {
+        let from = self.value();
+        let to = self.cell.down_until(predicate)?;
+        self.observers.backward(from, to, u64::from(to >= from));
+        Some(to)
+    }
+
+    /// Grows the boundary like [`BintCell::insert_at`]. Only a change is fired, since the
+    /// value moves to keep referring to the same member rather than stepping.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`BintCell::insert_at`], leaving the cell unchanged.
+    pub fn insert_at(&mut self, position: T) -> Result<T, BintError> {
+        let from = self.value();
+        let to = self.cell.insert_at(position)?;
+        self.observers.changed(from, to);
+        Ok(to)
+    }
+
+    /// Shrinks the boundary like [`BintCell::remove_at`]. Only a change is fired, as with
+    /// `insert_at`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`BintCell::remove_at`], leaving the cell unchanged.
+    pub fn remove_at(&mut self, position: T) -> Result<T, BintError> {
+        let from = self.value();
+        let to = self.cell.remove_at(position)?;
+        self.observers.changed(from, to);
+        Ok(to)
+    }
+
+    #[must_use]
+    pub fn distance_up(&self, other: &ObservedBintCell<T>) -> Option<T> {
+        self.cell.distance_up(&other.cell)
+    }
+
+    #[must_use]
+    pub fn distance_down(&self, other: &ObservedBintCell<T>) -> Option<T> {
+        self.cell.distance_down(&other.cell)
+    }
+
+    #[must_use]
+    pub fn signed_distance(&self, other: &ObservedBintCell<T>) -> Option<i128> {
+        self.cell.signed_distance(&other.cell)
+    }
+
+    /// Like [`BintCell::set`], a value that's out of range resets the cell to 0. Only a
+    /// change is fired, since setting doesn't wrap.
+    pub fn set(&self, value: T) {
+        self.changing(|cell| cell.set(value));
+    }
+
+    /// Like [`BintCell::try_set`], leaves the cell untouched and fires nothing when the value
+    /// is out of range.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of [`BintCell::try_set`].
+    pub fn try_set(&self, value: T) -> Result<(), BintError> {
+        self.changing(|cell| cell.try_set(value))
+    }
+
+    /// Skips the range check done by `set`. See [`Bint::new_unchecked`].
+    pub fn set_unchecked(&self, value: T) {
+        self.changing(|cell| cell.set_unchecked(value));
+    }
+
+    pub fn reset(&self) {
+        self.set(T::ZERO);
+    }
+
+    /// Moves up one spot under the policy, like [`BintCell::up_with`].
+    ///
+    /// The policy decides what happens at the ends of the range, so only a change is fired,
+    /// even under [`Wrapping`](crate::policy::Wrapping). Use `up_x` to hear about wraps.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use bint::event::{BintEvent, ObservedBintCell};
+    /// use bint::policy::Saturating;
+    ///
+    /// let events = Rc::new(RefCell::new(Vec::new()));
+    /// let volume: ObservedBintCell = ObservedBintCell::new(11);
+    /// volume.set(9);
+    ///
+    /// let log = Rc::clone(&events);
+    /// volume.add_observer(move |event| log.borrow_mut().push(event));
+    ///
+    /// assert_eq!(Ok(10), volume.up_with(&mut Saturating));
+    /// assert_eq!(Ok(10), volume.up_with(&mut Saturating));
+    /// assert_eq!(vec![BintEvent::Changed { from: 9, to: 10 }], *events.borrow());
+    /// ```
+    pub fn up_with<P: OverflowPolicy<T> + ?Sized>(&self, policy: &mut P) -> Result<T, BintError> {
+        self.up_x_with(1, policy)
+    }
+
+    /// Moves down one spot under the policy. See [`ObservedBintCell::up_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    pub fn down_with<P: OverflowPolicy<T> + ?Sized>(&self, policy: &mut P) -> Result<T, BintError> {
+        self.down_x_with(1, policy)
+    }
+
+    /// Moves up x spots under the policy. See [`ObservedBintCell::up_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    pub fn up_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<T, BintError> {
+        self.changing(|cell| cell.up_x_with(x, policy))
+    }
+
+    /// Moves down x spots under the policy. See [`ObservedBintCell::up_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    pub fn down_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<T, BintError> {
+        self.changing(|cell| cell.down_x_with(x, policy))
+    }
+
+    /// Returns a Bint version x number of spots up, leaving the cell as it is.
+    pub fn static_up_x(&self, x: u64) -> Bint<T> {
+        self.cell.static_up_x(x)
+    }
+
+    /// Returns a Bint version x number of spots down, leaving the cell as it is.
+    pub fn static_down_x(&self, x: u64) -> Bint<T> {
+        self.cell.static_down_x(x)
+    }
+
+    /// Returns the cell, dropping the observers.
+    #[must_use]
+    pub fn into_inner(self) -> BintCell<T> {
+        self.cell
+    }
+
+    /// Runs a change that can't wrap, firing a change if it moved the value.
+    fn changing<R>(&self, change: impl FnOnce(&BintCell<T>) -> R) -> R {
+        let from = self.value();
+        let result = change(&self.cell);
+        self.observers.changed(from, self.value());
+        result
+    }
+}
+
+impl<T: Unsigned> From<BintCell<T>> for ObservedBintCell<T> {
+    fn from(cell: BintCell<T>) -> ObservedBintCell<T> {
+        ObservedBintCell {
+            cell,
+            observers: Observers::new(),
+        }
+    }
+}
+
+impl<T: Unsigned> From<&ObservedBintCell<T>> for Bint<T> {
+    fn from(observed: &ObservedBintCell<T>) -> Bint<T> {
+        Bint::from(&observed.cell)
+    }
+}
+
+impl<T: Unsigned> fmt::Debug for ObservedBintCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ObservedBintCell")
+            .field("cell", &self.cell)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+impl<T: Unsigned> fmt::Display for ObservedBintCell<T> {
+    /// Writes the value, or `value/boundary` in the alternate form, like [`BintCell`].
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.cell, f)
+    }
+}
+
+/// `ObservedDrainableBintCell`: A [`DrainableBintCell`] that fires a [`BintEvent`] to its
+/// observers whenever its value changes or wraps, and when it runs out of capacity.
+///
+/// Usage:
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// use bint::event::{BintEvent, ObservedDrainableBintCell};
+/// use bint::DrainableBintCell;
+///
+/// let exhausted = Rc::new(Cell::new(false));
+/// let b: ObservedDrainableBintCell = DrainableBintCell::new(6, 2).into();
+///
+/// let flag = Rc::clone(&exhausted);
+/// b.add_observer(move |event| {
+///     if event == BintEvent::Exhausted {
+///         flag.set(true);
+///     }
+/// });
+///
+/// assert_eq!(Some(1), b.up());
+/// assert!(!exhausted.get());
+/// assert_eq!(Some(2), b.up());
+/// assert!(exhausted.get());
+/// ```
+pub struct ObservedDrainableBintCell<T: Unsigned = u8> {
+    cell: DrainableBintCell<T>,
+    observers: Observers<T>,
+}
+
+impl<T: Unsigned> ObservedDrainableBintCell<T> {
+    #[must_use]
+    pub fn new(boundary: T, capacity: usize) -> ObservedDrainableBintCell<T> {
+        ObservedDrainableBintCell::from(DrainableBintCell::new(boundary, capacity))
+    }
+
+    /// Registers an observer, to be called after every later step.
+    pub fn add_observer(&self, observer: impl BintObserver<T> + 'static) {
+        self.observers.add(observer);
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.cell.boundary()
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.cell.value()
+    }
+
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.cell.remaining()
+    }
+
+    #[must_use]
+    pub fn has_capacity(&self) -> bool {
+        self.cell.has_capacity()
+    }
+
+    /// The capacity it was created with, or last set to with `set_capacity`.
+    #[must_use]
+    pub fn original_capacity(&self) -> usize {
+        self.cell.original_capacity()
+    }
+
+    #[must_use]
+    pub fn used(&self) -> usize {
+        self.cell.used()
+    }
+
+    #[must_use]
+    pub fn max_capacity(&self) -> Option<usize> {
+        self.cell.max_capacity()
+    }
+
+    /// Adds to the capacity like [`DrainableBintCell::refill`], so that running out again
+    /// fires another `Exhausted`.
+    pub fn refill(&self, n: usize) -> usize {
+        self.cell.refill(n)
+    }
+
+    pub fn reset_capacity(&self) {
+        self.cell.reset_capacity();
+    }
+
+    /// Replaces the capacity like [`DrainableBintCell::set_capacity`]. Only steps fire
+    /// `Exhausted`, so setting the capacity to 0 doesn't.
+    ///
+    /// ```
+    /// use bint::event::ObservedDrainableBintCell;
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: ObservedDrainableBintCell = DrainableBintCell::new_with_max_capacity(6, 4, 8).into();
+    /// b.set_capacity(10);
+    ///
+    /// assert_eq!(8, b.remaining());
+    /// assert_eq!(8, b.original_capacity());
+    /// assert_eq!(Some(8), b.max_capacity());
+    /// ```
+    pub fn set_capacity(&self, capacity: usize) {
+        self.cell.set_capacity(capacity);
+    }
+
+    /// Removes one from the capacity without moving, like [`DrainableBintCell::drain`],
+    /// firing `Exhausted` if that was the last of it.
+    pub fn drain(&self) -> Option<usize> {
+        let remaining = self.cell.drain()?;
+        if remaining == 0 {
+            self.observers.notify(BintEvent::Exhausted);
+        }
+        Some(remaining)
+    }
+
+    #[must_use]
+    pub fn up(&self) -> Option<T> {
+        self.up_x(1)
+    }
+
+    #[must_use]
+    pub fn down(&self) -> Option<T> {
+        self.down_x(1)
+    }
+
+    /// Moves up x spots like [`DrainableBintCell::up_x`], moving as far as the capacity
+    /// allows before returning none.
+    #[must_use]
+    pub fn up_x(&self, x: u64) -> Option<T> {
+        self.up_with_carry(x).map(|(to, _)| to)
+    }
+
+    /// Moves down x spots like [`DrainableBintCell::down_x`], moving as far as the capacity
+    /// allows before returning none.
+    #[must_use]
+    pub fn down_x(&self, x: u64) -> Option<T> {
+        self.down_with_carry(x).map(|(to, _)| to)
+    }
+
+    /// Moves up for positive values of x and down for negative ones.
+    #[must_use]
+    pub fn step(&self, x: i64) -> Option<T> {
+        if x < 0 {
+            self.down_x(x.unsigned_abs())
+        } else {
+            self.up_x(x.unsigned_abs())
+        }
+    }
+
+    /// Moves up x spots like `up_x`, also returning how many times the value wrapped to 0.
+    #[must_use]
+    pub fn up_with_carry(&self, x: u64) -> Option<(T, u64)> {
+        let steps = self.steps(x);
+        let from = self.value();
+        let (to, laps) = self.cell.up_with_carry(steps)?;
+        let exhausted = self.exhausted(steps);
+        self.observers.forward(from, to, laps);
+        self.drained(exhausted, (to, laps), steps == x)
+    }
+
+    /// Moves down x spots like `down_x`, also returning how many times the value wrapped
+    /// below 0.
+    #[must_use]
+    pub fn down_with_carry(&self, x: u64) -> Option<(T, u64)> {
+        let steps = self.steps(x);
+        let from = self.value();
+        let (to, laps) = self.cell.down_with_carry(steps)?;
+        let exhausted = self.exhausted(steps);
+        self.observers.backward(from, to, laps);
+        self.drained(exhausted, (to, laps), steps == x)
+    }
+
+    /// Moves up for positive values of x and down for negative ones like `step`, also
+    /// returning the number of wraps. Wraps below 0 are counted as negative.
+    #[must_use]
+    pub fn step_with_carry(&self, x: i64) -> Option<(T, i64)> {
+        if x < 0 {
+            let (value, laps) = self.down_with_carry(x.unsigned_abs())?;
+            Some((value, i64::try_from(laps).map_or(i64::MIN, |laps| -laps)))
+        } else {
+            let (value, laps) = self.up_with_carry(x.unsigned_abs())?;
+            Some((value, i64::try_from(laps).unwrap_or(i64::MAX)))
+        }
+    }
+
+    /// Moves up one spot under the policy, like [`DrainableBintCell::up_with`]. As with
+    /// [`ObservedBintCell::up_with`], only a change is fired for the move, along with
+    /// `Exhausted` if it used up the last of the capacity.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    pub fn up_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.up_x_with(1, policy)
+    }
+
+    /// Moves down one spot under the policy. See [`ObservedDrainableBintCell::up_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    pub fn down_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.down_x_with(1, policy)
+    }
+
+    /// Moves up x spots under the policy, moving as far as the capacity allows before
+    /// returning `Ok(None)`. See [`ObservedDrainableBintCell::up_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    ///
+    /// ```
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    ///
+    /// use bint::event::{BintEvent, ObservedDrainableBintCell};
+    /// use bint::policy::Saturating;
+    ///
+    /// let events = Rc::new(RefCell::new(Vec::new()));
+    /// let b: ObservedDrainableBintCell = ObservedDrainableBintCell::new(6, 8);
+    ///
+    /// let log = Rc::clone(&events);
+    /// b.add_observer(move |event| log.borrow_mut().push(event));
+    ///
+    /// assert_eq!(Ok(Some(5)), b.up_x_with(7, &mut Saturating));
+    /// assert_eq!(Ok(None), b.up_x_with(2, &mut Saturating));
+    /// assert_eq!(
+    ///     vec![BintEvent::Changed { from: 0, to: 5 }, BintEvent::Exhausted],
+    ///     *events.borrow()
+    /// );
+    /// ```
+    pub fn up_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.stepping_with(x, |cell| cell.up_x_with(x, policy))
+    }
+
+    /// Moves down x spots under the policy. See [`ObservedDrainableBintCell::up_x_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    pub fn down_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.stepping_with(x, |cell| cell.down_x_with(x, policy))
+    }
+
+    /// Returns the cell, dropping the observers.
+    #[must_use]
+    pub fn into_inner(self) -> DrainableBintCell<T> {
+        self.cell
+    }
+
+    /// Takes a step under a policy, firing a change if it moved the value.
+    fn stepping_with(
+        &self,
+        x: u64,
+        step: impl FnOnce(&DrainableBintCell<T>) -> Result<Option<T>, BintError>,
+    ) -> Result<Option<T>, BintError> {
+        let steps = self.steps(x);
+        let from = self.value();
+        let completed = step(&self.cell)?.is_some();
+        let to = self.value();
+        let exhausted = self.exhausted(steps);
+        self.observers.changed(from, to);
+        Ok(self.drained(exhausted, to, completed))
+    }
+
+    /// How many of the x steps the remaining capacity allows.
+    fn steps(&self, x: u64) -> u64 {
+        let remaining = self.remaining();
+        let steps = usize::try_from(x).map_or(remaining, |x| x.min(remaining));
+        u64::try_from(steps).unwrap_or(x)
+    }
+
+    /// Whether the steps just taken used up the last of the capacity. Checked before the
+    /// observers run, since they may refill the cell.
+    fn exhausted(&self, steps: u64) -> bool {
+        steps > 0 && !self.has_capacity()
+    }
+
+    /// Fires `Exhausted` if the step used up the last of the capacity, and returns where the
+    /// step left the value if all of it was taken.
+    fn drained<R>(&self, exhausted: bool, to: R, completed: bool) -> Option<R> {
+        if exhausted {
+            self.observers.notify(BintEvent::Exhausted);
+        }
+        if completed {
+            Some(to)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Unsigned> From<DrainableBintCell<T>> for ObservedDrainableBintCell<T> {
+    fn from(cell: DrainableBintCell<T>) -> ObservedDrainableBintCell<T> {
+        ObservedDrainableBintCell {
+            cell,
+            observers: Observers::new(),
+        }
+    }
+}
+
+impl<T: Unsigned> From<&ObservedDrainableBintCell<T>> for Bint<T> {
+    fn from(observed: &ObservedDrainableBintCell<T>) -> Bint<T> {
+        Bint::from(&observed.cell)
+    }
+}
+
+impl<T: Unsigned> fmt::Debug for ObservedDrainableBintCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ObservedDrainableBintCell")
+            .field("cell", &self.cell)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+impl<T: Unsigned> fmt::Display for ObservedDrainableBintCell<T> {
+    /// Writes the value, or `value/boundary [cap capacity]` in the alternate form, like
+    /// [`DrainableBintCell`].
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.cell, f)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::policy::{Erroring, Saturating, Wrapping};
+
+    type Log<T> = Rc<RefCell<Vec<BintEvent<T>>>>;
+
+    fn logged<T: Unsigned>() -> (Log<T>, impl FnMut(BintEvent<T>) + 'static) {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let log = Rc::clone(&events);
+        (events, move |event| log.borrow_mut().push(event))
+    }
+
+    #[test]
+    fn wraps_both_ways() {
+        let (events, observer) = logged();
+        let b: ObservedBintCell = ObservedBintCell::new(5);
+        b.add_observer(observer);
+
+        b.down_x(11);
+        b.step(-4);
+        b.set(0);
+        b.up_x(5);
+        b.reset();
+
+        assert_eq!(
+            vec![
+                BintEvent::Changed { from: 0, to: 4 },
+                BintEvent::WrappedBackward { laps: 3 },
+                BintEvent::Changed { from: 4, to: 0 },
+                BintEvent::WrappedForward { laps: 1 },
+            ],
+            *events.borrow()
+        );
+    }
+
+    #[test]
+    fn observer_moves_its_own_cell() {
+        let (events, observer) = logged();
+        let b: Rc<ObservedBintCell> = Rc::new(ObservedBintCell::new(4));
+
+        // Skips 0 by stepping again whenever the value wraps forward.
+        let cell = Rc::downgrade(&b);
+        b.add_observer(move |event| {
+            if let BintEvent::WrappedForward { .. } = event {
+                cell.upgrade().unwrap().up();
+            }
+        });
+        let (late, late_observer) = logged();
+        let mut late_observer = Some(late_observer);
+        let cell = Rc::downgrade(&b);
+        b.add_observer(move |_| {
+            if let Some(observer) = late_observer.take() {
+                cell.upgrade().unwrap().add_observer(observer);
+            }
+        });
+        b.add_observer(observer);
+
+        assert_eq!(0, b.up_x(4));
+        assert_eq!(1, b.value());
+        b.set(3);
+
+        assert_eq!(
+            vec![
+                BintEvent::WrappedForward { laps: 1 },
+                BintEvent::Changed { from: 0, to: 1 },
+                BintEvent::Changed { from: 1, to: 3 },
+            ],
+            *events.borrow()
+        );
+        assert_eq!(
+            vec![
+                BintEvent::Changed { from: 0, to: 1 },
+                BintEvent::Changed { from: 1, to: 3 },
+            ],
+            *late.borrow()
+        );
+    }
+
+    #[test]
+    fn step_returns_its_own_result() {
+        let b: Rc<ObservedDrainableBintCell> = Rc::new(ObservedDrainableBintCell::new(6, 3));
+        let cell = Rc::downgrade(&b);
+        b.add_observer(move |event| {
+            if let BintEvent::Changed { to: 1, .. } = event {
+                let _ = cell.upgrade().unwrap().up();
+            }
+        });
+
+        assert_eq!(Some(1), b.up());
+        assert_eq!(2, b.value());
+        assert_eq!(None, b.up_x(2));
+        assert_eq!(3, b.value());
+    }
+
+    #[test]
+    fn observers_survive_a_panic() {
+        let (events, observer) = logged();
+        let b: ObservedBintCell = ObservedBintCell::new(3);
+        b.add_observer(|event| {
+            if let BintEvent::WrappedForward { .. } = event {
+                panic!("wrapped");
+            }
+        });
+        b.add_observer(observer);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| b.up_x(3)));
+        assert!(result.is_err());
+        b.up();
+
+        assert_eq!(
+            vec![BintEvent::Changed { from: 0, to: 1 }],
+            *events.borrow()
+        );
+        assert_eq!(
+            "ObservedBintCell { cell: BintCell { cell: Cell { value: 1 }, boundary: 3 }, observers: 2 }",
+            format!("{:?}", b)
+        );
+    }
+
+    #[test]
+    fn forwards_every_step() {
+        let (events, observer) = logged();
+        let mut b: ObservedBintCell = ObservedBintCell::new(4);
+        b.add_observer(observer);
+
+        assert_eq!((1, -2), b.step_with_carry(-7));
+        assert_eq!(Some(0), b.up_until(|s| s == 0));
+        assert_eq!(Some(0), b.down_until(|s| s == 0));
+        assert_eq!(Ok(0), b.insert_at(2));
+        assert_eq!(Ok(1), b.insert_at(0));
+        assert_eq!(Ok(0), b.remove_at(0));
+        assert!(b.try_set(5).is_err());
+        assert_eq!(Ok(()), b.try_set(4));
+        assert_eq!(Ok(4), b.up_with(&mut Saturating));
+        assert_eq!(Ok(0), b.up_with(&mut Wrapping));
+
+        assert_eq!(
+            vec![
+                BintEvent::Changed { from: 0, to: 1 },
+                BintEvent::WrappedBackward { laps: 2 },
+                BintEvent::Changed { from: 1, to: 0 },
+                BintEvent::WrappedForward { laps: 1 },
+                BintEvent::WrappedBackward { laps: 1 },
+                BintEvent::Changed { from: 0, to: 1 },
+                BintEvent::Changed { from: 1, to: 0 },
+                BintEvent::Changed { from: 0, to: 4 },
+                BintEvent::Changed { from: 4, to: 0 },
+            ],
+            *events.borrow()
+        );
+        assert_eq!(5, b.boundary());
+    }
+
+    #[test]
+    fn drainable_forwards_every_step() {
+        let (events, observer) = logged();
+        let b: ObservedDrainableBintCell = ObservedDrainableBintCell::new(3, 4);
+        b.add_observer(observer);
+
+        assert_eq!(Some((1, 1)), b.up_with_carry(4));
+        assert_eq!(None, b.step_with_carry(-1));
+        b.refill(2);
+        assert!(b.down_x_with(2, &mut Erroring).is_err());
+        assert_eq!(Ok(Some(0)), b.down_with(&mut Erroring));
+        assert_eq!(Some(0), b.drain());
+        assert_eq!(None, b.drain());
+
+        assert_eq!(
+            vec![
+                BintEvent::Changed { from: 0, to: 1 },
+                BintEvent::WrappedForward { laps: 1 },
+                BintEvent::Exhausted,
+                BintEvent::Changed { from: 1, to: 0 },
+                BintEvent::Exhausted,
+            ],
+            *events.borrow()
+        );
+    }
+
+    #[test]
+    fn exhausted_once_per_refill() {
+        let (events, observer) = logged();
+        let b: ObservedDrainableBintCell = ObservedDrainableBintCell::new(3, 2);
+        b.add_observer(observer);
+
+        assert_eq!(None, b.down_x(3));
+        assert_eq!(None, b.up());
+        b.refill(1);
+        assert_eq!(Some(2), b.up());
+
+        assert_eq!(
+            vec![
+                BintEvent::Changed { from: 0, to: 1 },
+                BintEvent::WrappedBackward { laps: 1 },
+                BintEvent::Exhausted,
+                BintEvent::Changed { from: 1, to: 2 },
+                BintEvent::Exhausted,
+            ],
+            *events.borrow()
+        );
+    }
+}
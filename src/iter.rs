@@ -0,0 +1,278 @@
+//! Iterators over the values of a [`Bint`].
+
+use std::convert::TryFrom;
+use std::iter::FusedIterator;
+
+use crate::{Bint, Unsigned};
+
+impl<T: Unsigned> Bint<T> {
+    /// One full cycle of values going up, starting with the current one.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(4, 2);
+    /// let seats: Vec<u8> = seat.lap().map(|b| b.value()).collect();
+    ///
+    /// assert_eq!(vec![2, 3, 0, 1], seats);
+    /// assert_eq!(4, seat.lap().len());
+    /// ```
+    #[must_use]
+    pub fn lap(self) -> Lap<T> {
+        Lap::new(self, false)
+    }
+
+    /// One full cycle of values going down, starting with the current one.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(4, 2);
+    /// let seats: Vec<u8> = seat.lap_rev().map(|b| b.value()).collect();
+    ///
+    /// assert_eq!(vec![2, 1, 0, 3], seats);
+    /// ```
+    #[must_use]
+    pub fn lap_rev(self) -> Lap<T> {
+        Lap::new(self, true)
+    }
+
+    /// Every value from 0 up to the boundary, regardless of the current value.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(4, 2);
+    /// let seats: Vec<u8> = seat.all_values().rev().map(|b| b.value()).collect();
+    ///
+    /// assert_eq!(vec![3, 2, 1, 0], seats);
+    /// ```
+    #[must_use]
+    pub fn all_values(self) -> Lap<T> {
+        Bint::new(self.boundary()).lap()
+    }
+
+    /// Endlessly goes up, starting with the current value.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(3, 1);
+    /// let seats: Vec<u8> = seat.iter_up().take(5).map(|b| b.value()).collect();
+    ///
+    /// assert_eq!(vec![1, 2, 0, 1, 2], seats);
+    /// ```
+    #[must_use]
+    pub fn iter_up(self) -> IterUp<T> {
+        IterUp { next: self }
+    }
+
+    /// Endlessly goes down, starting with the current value.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(3, 1);
+    /// let seats: Vec<u8> = seat.iter_down().take(5).map(|b| b.value()).collect();
+    ///
+    /// assert_eq!(vec![1, 0, 2, 1, 0], seats);
+    /// ```
+    #[must_use]
+    pub fn iter_down(self) -> IterDown<T> {
+        IterDown { next: self }
+    }
+}
+
+/// One full cycle of a [`Bint`]'s values. Created by [`Bint::lap`], [`Bint::lap_rev`] and
+/// [`Bint::all_values`].
+///
+/// Implements `ExactSizeIterator` for the integer types whose boundaries always fit in a
+/// `usize`, in the same way as the standard library's ranges.
+#[derive(Clone, Debug)]
+pub struct Lap<T: Unsigned = u8> {
+    origin: Bint<T>,
+    reverse: bool,
+    front: u128,
+    back: u128,
+}
+
+impl<T: Unsigned> Lap<T> {
+    fn new(origin: Bint<T>, reverse: bool) -> Lap<T> {
+        Lap {
+            origin,
+            reverse,
+            front: 0,
+            back: origin.boundary().to_u128(),
+        }
+    }
+
+    fn get(&self, offset: u128) -> Bint<T> {
+        if self.reverse {
+            self.origin.down_wide(offset)
+        } else {
+            self.origin.up_wide(offset)
+        }
+    }
+
+    fn remaining(&self) -> u128 {
+        self.back - self.front
+    }
+}
+
+impl<T: Unsigned> Iterator for Lap<T> {
+    type Item = Bint<T>;
+
+    fn next(&mut self) -> Option<Bint<T>> {
+        if self.front == self.back {
+            return None;
+        }
+        let bint = self.get(self.front);
+        self.front += 1;
+        Some(bint)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Bint<T>> {
+        self.front += self.remaining().min(n as u128);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.remaining();
+        (
+            usize::try_from(remaining).unwrap_or(usize::MAX),
+            usize::try_from(remaining).ok(),
+        )
+    }
+}
+
+impl<T: Unsigned> DoubleEndedIterator for Lap<T> {
+    fn next_back(&mut self) -> Option<Bint<T>> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+}
+
+impl<T: Unsigned> FusedIterator for Lap<T> {}
+
+macro_rules! impl_exact_size {
+    ($($t:ty),*) => {
+        $(
+            impl ExactSizeIterator for Lap<$t> {}
+        )*
+    };
+}
+
+impl_exact_size!(u8, u16, u32, usize);
+
+/// Endlessly goes up through a [`Bint`]'s values. Created by [`Bint::iter_up`].
+#[derive(Clone, Debug)]
+pub struct IterUp<T: Unsigned = u8> {
+    next: Bint<T>,
+}
+
+impl<T: Unsigned> Iterator for IterUp<T> {
+    type Item = Bint<T>;
+
+    fn next(&mut self) -> Option<Bint<T>> {
+        let bint = self.next;
+        self.next = bint.up();
+        Some(bint)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Bint<T>> {
+        self.next = self.next.up_wide(n as u128);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Unsigned> FusedIterator for IterUp<T> {}
+
+/// Endlessly goes down through a [`Bint`]'s values. Created by [`Bint::iter_down`].
+#[derive(Clone, Debug)]
+pub struct IterDown<T: Unsigned = u8> {
+    next: Bint<T>,
+}
+
+impl<T: Unsigned> Iterator for IterDown<T> {
+    type Item = Bint<T>;
+
+    fn next(&mut self) -> Option<Bint<T>> {
+        let bint = self.next;
+        self.next = bint.down();
+        Some(bint)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Bint<T>> {
+        self.next = self.next.down_wide(n as u128);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Unsigned> FusedIterator for IterDown<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lap_matches_up() {
+        let b: Bint = Bint::new_with_value(7, 3);
+        let mut stepped = b;
+
+        for bint in b.lap() {
+            assert_eq!(stepped, bint);
+            stepped = stepped.up();
+        }
+        assert_eq!(b, stepped);
+    }
+
+    #[test]
+    fn lap_both_ends() {
+        let b: Bint<u16> = Bint::new_with_value(5, 3);
+        let mut lap = b.lap_rev();
+
+        assert_eq!(Some(3), lap.next().map(|b| b.value()));
+        assert_eq!(Some(4), lap.next_back().map(|b| b.value()));
+        assert_eq!(3, lap.len());
+        assert_eq!(Some(0), lap.nth(2).map(|b| b.value()));
+        assert_eq!(None, lap.next());
+        assert_eq!(None, lap.next_back());
+    }
+
+    #[test]
+    fn lap_empty() {
+        let b: Bint = Bint::new(0);
+
+        assert_eq!(0, b.lap().len());
+        assert_eq!(None, b.all_values().next());
+    }
+
+    #[test]
+    fn lap_wide() {
+        let b: Bint<u128> = Bint::new(u128::MAX);
+        let mut lap = b.lap();
+
+        assert_eq!((usize::MAX, None), lap.size_hint());
+        assert_eq!(Some(u128::MAX - 1), lap.next_back().map(|b| b.value()));
+        assert_eq!(Some(10), lap.nth(10).map(|b| b.value()));
+    }
+
+    #[test]
+    fn iter_nth() {
+        let b: Bint = Bint::new_with_value(6, 2);
+
+        assert_eq!(b.iter_up().nth(1_000), Some(b.up_x(1_000)));
+        assert_eq!(b.iter_down().nth(1_000), Some(b.down_x(1_000)));
+    }
+}
@@ -1,35 +1,80 @@
 #![warn(clippy::pedantic, clippy::unwrap_used, clippy::expect_used)]
-#![allow(clippy::needless_doctest_main)]
+#![allow(clippy::needless_doctest_main, clippy::uninlined_format_args)]
 #![cfg_attr(doc, doc = include_str!("../README.md"))]
 
 use std::cell::Cell;
+use std::convert::TryFrom;
 use std::fmt;
 
+mod atomic;
+pub mod bucket;
+mod const_bint;
+pub mod crt;
+mod error;
+pub mod event;
+mod iter;
+mod modular;
+mod odometer;
+mod ops;
+mod parse;
+pub mod policy;
+pub mod radix;
+mod ranged;
+mod ring;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod table;
+mod unsigned;
+
+#[cfg(target_has_atomic = "64")]
+pub use atomic::AtomicDrainableBint;
+pub use atomic::{AtomicBint, AtomicUnsigned};
+pub use const_bint::{ConstBint, ConstBintCell};
+pub use error::{BintError, ParseBintError};
+pub use iter::{IterDown, IterUp, Lap};
+pub use odometer::Odometer;
+pub use ranged::{RangedBint, RangedBintCell};
+pub use ring::Ring;
+pub use unsigned::Unsigned;
+
 /// Bint: A bounded integer.
 ///
 /// Returns a struct that represents an unsigned integer and a boundary that represents when
-/// the value will be reset to 0.
+/// the value will be reset to 0. The integer type defaults to `u8`, but any of the
+/// [`Unsigned`] types can be used.
+///
+/// The value is always below the boundary, apart from a zero boundary, where it is always 0.
 ///
 /// Usage:
 ///
 /// ```
 /// use bint::Bint;
 ///
-/// let b = Bint {value: 5, boundary: 6 };
+/// let b: Bint = Bint::new_with_value(6, 5);
 /// let c: Bint = b.up();
 /// let d: Bint = c.up();
 ///
-/// assert_eq!(5, b.value);
-/// assert_eq!(0, c.value);
-/// assert_eq!(1, d.value);
+/// assert_eq!(5, b.value());
+/// assert_eq!(0, c.value());
+/// assert_eq!(1, d.value());
+/// ```
+///
+/// Wider integer types work the same way:
+///
+/// ```
+/// use bint::Bint;
+///
+/// let b: Bint<u16> = Bint::new(1_000);
+///
+/// assert_eq!(999, b.down().value());
 /// ```
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct Bint {
-    pub value: u8,
-    pub boundary: u8,
+pub struct Bint<T: Unsigned = u8> {
+    value: T,
+    boundary: T,
 }
 
-impl Bint {
+impl<T: Unsigned> Bint<T> {
     /// ```
     /// use bint::Bint;
     ///
@@ -38,29 +83,32 @@ impl Bint {
     /// let d: Bint = c.up();
     /// let e: Bint = d.up();
     ///
-    /// assert_eq!(0, b.value);
-    /// assert_eq!(5, c.value);
-    /// assert_eq!(0, d.value);
-    /// assert_eq!(1, e.value);
+    /// assert_eq!(0, b.value());
+    /// assert_eq!(5, c.value());
+    /// assert_eq!(0, d.value());
+    /// assert_eq!(1, e.value());
     /// ```
     #[must_use]
-    pub fn new(boundary: u8) -> Bint {
-        Bint { value: 0, boundary }
+    pub fn new(boundary: T) -> Bint<T> {
+        Bint {
+            value: T::ZERO,
+            boundary,
+        }
     }
 
     /// ```
     /// use bint::Bint;
     ///
-    /// let bint = Bint::new_with_value(10, 7);
-    /// assert_eq!(10, bint.boundary);
-    /// assert_eq!(7, bint.value);
+    /// let bint: Bint = Bint::new_with_value(10, 7);
+    /// assert_eq!(10, bint.boundary());
+    /// assert_eq!(7, bint.value());
     ///
-    /// let bint_out_of_range = Bint::new_with_value(10, 23);
-    /// assert_eq!(10, bint_out_of_range.boundary);
-    /// assert_eq!(0, bint_out_of_range.value);
+    /// let bint_out_of_range: Bint<u16> = Bint::new_with_value(10, 23);
+    /// assert_eq!(10, bint_out_of_range.boundary());
+    /// assert_eq!(0, bint_out_of_range.value());
     /// ```
     #[must_use]
-    pub fn new_with_value(boundary: u8, value: u8) -> Bint {
+    pub fn new_with_value(boundary: T, value: T) -> Bint<T> {
         if value >= boundary {
             Bint::new(boundary)
         } else {
@@ -68,25 +116,82 @@ impl Bint {
         }
     }
 
+    /// Unlike `new_with_value`, rejects a value that's out of range instead of resetting it
+    /// to 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the boundary is 0, and
+    /// `BintError::ValueOutOfRange` if the value isn't below the boundary.
+    ///
+    /// ```
+    /// use bint::{Bint, BintError};
+    ///
+    /// let bint: Bint = Bint::try_new(10, 7).unwrap();
+    /// assert_eq!(7, bint.value());
+    ///
+    /// assert_eq!(Err(BintError::ZeroBoundary), Bint::<u8>::try_new(0, 0));
+    /// assert_eq!(
+    ///     Err(BintError::ValueOutOfRange { value: 23, boundary: 10 }),
+    ///     Bint::<u8>::try_new(10, 23)
+    /// );
+    /// ```
+    pub fn try_new(boundary: T, value: T) -> Result<Bint<T>, BintError> {
+        if boundary == T::ZERO {
+            Err(BintError::ZeroBoundary)
+        } else if value >= boundary {
+            Err(BintError::ValueOutOfRange {
+                value: value.to_u128(),
+                boundary: boundary.to_u128(),
+            })
+        } else {
+            Ok(Bint { value, boundary })
+        }
+    }
+
+    /// Skips the range check done by the other constructors. The caller is responsible for
+    /// the value being below the boundary; if it isn't, the results of stepping are
+    /// unspecified and may panic.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let b: Bint = Bint::new_unchecked(6, 5);
+    ///
+    /// assert_eq!(0, b.up().value());
+    /// ```
+    #[must_use]
+    pub fn new_unchecked(boundary: T, value: T) -> Bint<T> {
+        Bint { value, boundary }
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.boundary
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
     /// ```
     /// use bint::Bint;
     ///
-    /// let b: Bint = Bint {
-    ///     value: 4,
-    ///     boundary: 6,
-    /// };
+    /// let b: Bint = Bint::new_with_value(6, 4);
     ///
     /// let b: Bint = b.up();
-    /// assert_eq!(5, b.value);
+    /// assert_eq!(5, b.value());
     ///
     /// let b: Bint = b.up();
-    /// assert_eq!(0, b.value);
+    /// assert_eq!(0, b.value());
     /// ```
     #[must_use]
-    pub fn up(&self) -> Bint {
-        let v = match self.boundary {
-            0 => 0,
-            _ => (self.value + 1) % self.boundary,
+    pub fn up(&self) -> Bint<T> {
+        let v = if self.boundary == T::ZERO {
+            T::ZERO
+        } else {
+            (self.value + T::ONE) % self.boundary
         };
         Bint {
             value: v,
@@ -97,51 +202,44 @@ impl Bint {
     /// ```
     /// use bint::Bint;
     ///
-    /// let b: Bint = Bint {
-    ///     value: 4,
-    ///     boundary: 6,
-    /// };
+    /// let b: Bint = Bint::new_with_value(6, 4);
     ///
     /// let b: Bint = b.up_x(3);
-    /// assert_eq!(1, b.value);
+    /// assert_eq!(1, b.value());
+    ///
+    /// let b: Bint = b.up_x(6_000_000_001);
+    /// assert_eq!(2, b.value());
     /// ```
     #[must_use]
-    pub fn up_x(self, x: u8) -> Bint {
-        let mut up = self;
-        for _ in 0..x {
-            up = up.up();
-        }
-        up
+    pub fn up_x(self, x: u64) -> Bint<T> {
+        self.up_wide(u128::from(x))
     }
 
     /// ```
     /// use bint::Bint;
     ///
-    /// let b: Bint = Bint {
-    ///     value: 1,
-    ///     boundary: 6,
-    /// };
+    /// let b: Bint = Bint::new_with_value(6, 1);
     ///
     /// let b: Bint = b.down();
-    /// assert_eq!(0, b.value);
+    /// assert_eq!(0, b.value());
     ///
     /// let b: Bint = b.down();
-    /// assert_eq!(5, b.value);
+    /// assert_eq!(5, b.value());
     /// ```
     #[must_use]
-    pub fn down(&self) -> Bint {
+    pub fn down(&self) -> Bint<T> {
         // This deals with the issue where someone creates a default Bint with a zero boundqry
         // triggering a divide by zero error.
-        if self.boundary == 0 {
+        if self.boundary == T::ZERO {
             return *self;
         }
-        if self.value == 0 {
+        if self.value == T::ZERO {
             return Bint {
-                value: self.boundary - 1,
+                value: self.boundary - T::ONE,
                 boundary: self.boundary,
             };
         }
-        let v = (self.value - 1) % self.boundary;
+        let v = (self.value - T::ONE) % self.boundary;
         Bint {
             value: v,
             boundary: self.boundary,
@@ -151,34 +249,323 @@ impl Bint {
     /// ```
     /// use bint::Bint;
     ///
-    /// let b: Bint = Bint {
-    ///     value: 4,
-    ///     boundary: 6,
-    /// };
+    /// let b: Bint = Bint::new_with_value(6, 4);
     ///
     /// let b: Bint = b.down_x(6);
-    /// assert_eq!(4, b.value);
+    /// assert_eq!(4, b.value());
     ///
     /// let b: Bint = b.down_x(3);
-    /// assert_eq!(1, b.value);
+    /// assert_eq!(1, b.value());
+    ///
+    /// let b: Bint = b.down_x(6_000_000_001);
+    /// assert_eq!(0, b.value());
+    /// ```
+    #[must_use]
+    pub fn down_x(self, x: u64) -> Bint<T> {
+        self.down_wide(u128::from(x))
+    }
+
+    /// Moves up for positive values of x and down for negative ones.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let b: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!(1, b.step(3).value());
+    /// assert_eq!(1, b.step(-3).value());
+    /// assert_eq!(2, b.step(i64::MIN).value());
+    /// ```
+    #[must_use]
+    pub fn step(self, x: i64) -> Bint<T> {
+        if x < 0 {
+            self.down_x(x.unsigned_abs())
+        } else {
+            self.up_x(x.unsigned_abs())
+        }
+    }
+
+    /// Moves up x spots, also returning how many times the value wrapped to 0.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let button: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!((Bint::new_with_value(6, 5), 0), button.up_with_carry(1));
+    /// assert_eq!((Bint::new_with_value(6, 0), 1), button.up_with_carry(2));
+    /// assert_eq!((Bint::new_with_value(6, 4), 3), button.up_with_carry(18));
+    /// ```
+    #[must_use]
+    pub fn up_with_carry(self, x: u64) -> (Bint<T>, u64) {
+        if x == 0 {
+            return (self, 0);
+        }
+        if self.boundary == T::ZERO {
+            return (Bint::new(self.boundary), 0);
+        }
+        let (value, laps) =
+            modular::up_with_carry(self.value.to_u128(), u128::from(x), self.boundary.to_u128());
+        (self.with_value(value), u64::try_from(laps).unwrap_or(x))
+    }
+
+    /// Moves down x spots, also returning how many times the value wrapped below 0.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let button: Bint = Bint::new_with_value(6, 1);
+    ///
+    /// assert_eq!((Bint::new_with_value(6, 0), 0), button.down_with_carry(1));
+    /// assert_eq!((Bint::new_with_value(6, 5), 1), button.down_with_carry(2));
+    /// assert_eq!((Bint::new_with_value(6, 1), 3), button.down_with_carry(18));
+    /// ```
+    #[must_use]
+    pub fn down_with_carry(self, x: u64) -> (Bint<T>, u64) {
+        if x == 0 || self.boundary == T::ZERO {
+            return (self, 0);
+        }
+        let (value, laps) =
+            modular::down_with_carry(self.value.to_u128(), u128::from(x), self.boundary.to_u128());
+        (self.with_value(value), u64::try_from(laps).unwrap_or(x))
+    }
+
+    /// Moves up for positive values of x and down for negative ones, also returning the
+    /// number of wraps. Wraps below 0 are counted as negative.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let button: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!((Bint::new_with_value(6, 1), 1), button.step_with_carry(3));
+    /// assert_eq!((Bint::new_with_value(6, 4), -1), button.step_with_carry(-6));
+    /// assert_eq!((Bint::new_with_value(6, 5), -1), button.step_with_carry(-5));
+    /// ```
+    #[must_use]
+    pub fn step_with_carry(self, x: i64) -> (Bint<T>, i64) {
+        if x < 0 {
+            let (bint, laps) = self.down_with_carry(x.unsigned_abs());
+            // Only i64::MIN steps on a boundary of 1 borrow more than i64::MAX times.
+            (bint, i64::try_from(laps).map_or(i64::MIN, |laps| -laps))
+        } else {
+            let (bint, laps) = self.up_with_carry(x.unsigned_abs());
+            (bint, i64::try_from(laps).unwrap_or(i64::MAX))
+        }
+    }
+
+    /// Moves up to the next value the predicate accepts, checking each value at most once and
+    /// the current one last. Returns `None` if no value is accepted.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let folded = [false, true, true, false, true, false];
+    /// let seat: Bint = Bint::new_with_value(6, 0);
+    ///
+    /// let seat = seat.up_until(|s| !folded[usize::from(s)]).unwrap();
+    /// assert_eq!(3, seat.value());
+    /// assert_eq!(Some(5), seat.up_until(|s| !folded[usize::from(s)]).map(|b| b.value()));
+    /// assert_eq!(None, seat.up_until(|_| false));
+    /// ```
+    pub fn up_until<P: FnMut(T) -> bool>(self, mut predicate: P) -> Option<Bint<T>> {
+        self.up().lap().find(|bint| predicate(bint.value))
+    }
+
+    /// Moves down to the next value the predicate accepts, checking each value at most once and
+    /// the current one last. Returns `None` if no value is accepted.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(6, 1);
+    ///
+    /// assert_eq!(Some(4), seat.down_until(|s| s % 2 == 0 && s > 2).map(|b| b.value()));
+    /// assert_eq!(Some(1), seat.down_until(|s| s == 1).map(|b| b.value()));
+    /// ```
+    pub fn down_until<P: FnMut(T) -> bool>(self, mut predicate: P) -> Option<Bint<T>> {
+        self.down().lap_rev().find(|bint| predicate(bint.value))
+    }
+
+    /// Grows the boundary by one to make room for a new member at `position`, moving the value
+    /// so that it still refers to the same member.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::Overflow` if the boundary is already the integer type's maximum, and
+    /// `BintError::ValueOutOfRange` if the position is beyond the new boundary.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(4, 2);
+    ///
+    /// assert_eq!(Ok(Bint::new_with_value(5, 3)), seat.insert_at(1));
+    /// assert_eq!(Ok(Bint::new_with_value(5, 2)), seat.insert_at(4));
+    /// assert!(seat.insert_at(5).is_err());
+    /// ```
+    pub fn insert_at(self, position: T) -> Result<Bint<T>, BintError> {
+        if self.boundary == T::MAX {
+            return Err(BintError::Overflow);
+        }
+        let boundary = self.boundary + T::ONE;
+        if position >= boundary {
+            return Err(BintError::ValueOutOfRange {
+                value: position.to_u128(),
+                boundary: boundary.to_u128(),
+            });
+        }
+        let value = if self.boundary != T::ZERO && self.value >= position {
+            self.value + T::ONE
+        } else {
+            self.value
+        };
+        Ok(Bint { value, boundary })
+    }
+
+    /// Shrinks the boundary by one, removing the member at `position` and moving the value so
+    /// that it still refers to the same member. If the current member is removed, the value
+    /// moves to the one after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if the position isn't below the boundary.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(4, 2);
+    ///
+    /// assert_eq!(Ok(Bint::new_with_value(3, 1)), seat.remove_at(0));
+    /// assert_eq!(Ok(Bint::new_with_value(3, 2)), seat.remove_at(2));
+    /// assert_eq!(Ok(Bint::new_with_value(3, 0)), seat.up().remove_at(3));
+    /// assert!(seat.remove_at(4).is_err());
+    /// ```
+    pub fn remove_at(self, position: T) -> Result<Bint<T>, BintError> {
+        if position >= self.boundary {
+            return Err(BintError::ValueOutOfRange {
+                value: position.to_u128(),
+                boundary: self.boundary.to_u128(),
+            });
+        }
+        let boundary = self.boundary - T::ONE;
+        let value = if self.value > position {
+            self.value - T::ONE
+        } else if self.value == boundary {
+            T::ZERO
+        } else {
+            self.value
+        };
+        Ok(Bint { value, boundary })
+    }
+
+    /// The number of steps up from this `Bint` to the other one, or `None` if their boundaries
+    /// differ.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!(Some(3), a.distance_up(Bint::new_with_value(6, 1)));
+    /// assert_eq!(Some(0), a.distance_up(a));
+    /// assert_eq!(None, a.distance_up(Bint::new_with_value(7, 1)));
+    /// ```
+    #[must_use]
+    pub fn distance_up(self, other: Bint<T>) -> Option<T> {
+        other.checked_sub(self).map(|bint| bint.value)
+    }
+
+    /// The number of steps down from this `Bint` to the other one, or `None` if their
+    /// boundaries differ.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!(Some(3), a.distance_down(Bint::new_with_value(6, 1)));
+    /// assert_eq!(Some(5), a.distance_down(Bint::new_with_value(6, 5)));
+    /// ```
+    #[must_use]
+    pub fn distance_down(self, other: Bint<T>) -> Option<T> {
+        self.checked_sub(other).map(|bint| bint.value)
+    }
+
+    /// The shortest number of steps from this `Bint` to the other one, positive going up and
+    /// negative going down, or `None` if their boundaries differ.
+    ///
+    /// The result is in `-(n/2)..=(n-1)/2` for a boundary of `n`, using integer division. Only
+    /// an even boundary has a point exactly halfway around, and it counts as going down, which
+    /// is why the range reaches one further below 0 than above it.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!(Some(2), a.signed_distance(Bint::new_with_value(6, 0)));
+    /// assert_eq!(Some(-2), a.signed_distance(Bint::new_with_value(6, 2)));
+    /// assert_eq!(Some(-3), a.signed_distance(Bint::new_with_value(6, 1)));
+    ///
+    /// let b: Bint = Bint::new(7);
+    ///
+    /// assert_eq!(Some(3), b.signed_distance(Bint::new_with_value(7, 3)));
+    /// assert_eq!(Some(-3), b.signed_distance(Bint::new_with_value(7, 4)));
     /// ```
     #[must_use]
-    pub fn down_x(self, x: u8) -> Bint {
-        let mut down = self;
-        for _ in 0..x {
-            down = down.down();
+    // Both branches are at most n / 2 away from 0, which always fits in an i128.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn signed_distance(self, other: Bint<T>) -> Option<i128> {
+        let distance = self.distance_up(other)?.to_u128();
+        let boundary = self.boundary.to_u128();
+        if distance < boundary - boundary / 2 {
+            Some(distance as i128)
+        } else {
+            Some(-((boundary - distance) as i128))
+        }
+    }
+
+    pub(crate) fn up_wide(self, x: u128) -> Bint<T> {
+        if x == 0 {
+            return self;
+        }
+        if self.boundary == T::ZERO {
+            return Bint::new(self.boundary);
+        }
+        self.with_value(modular::up(
+            self.value.to_u128(),
+            x,
+            self.boundary.to_u128(),
+        ))
+    }
+
+    pub(crate) fn down_wide(self, x: u128) -> Bint<T> {
+        if x == 0 || self.boundary == T::ZERO {
+            return self;
+        }
+        self.with_value(modular::down(
+            self.value.to_u128(),
+            x,
+            self.boundary.to_u128(),
+        ))
+    }
+
+    fn with_value(self, value: u128) -> Bint<T> {
+        Bint {
+            value: T::from_u128(value),
+            boundary: self.boundary,
         }
-        down
     }
 }
 
-impl Default for Bint {
-    /// Defaults to the maximum value of an unsigned 8 integer.
+impl<T: Unsigned> Default for Bint<T> {
+    /// Defaults to a boundary of the maximum value of the integer type.
     ///
     /// ```
     /// use bint::Bint;
     ///
-    /// let mut b = Bint::default();
+    /// let mut b: Bint = Bint::default();
     ///
     /// for _ in 0..u8::MAX {
     ///     b = b.down()
@@ -188,35 +575,52 @@ impl Default for Bint {
     ///     b = b.down()
     /// }
     ///
-    /// assert_eq!(b.value, 0)
+    /// assert_eq!(b.value(), 0)
     /// ```
     fn default() -> Self {
         Bint {
-            value: 0,
-            boundary: u8::MAX,
+            value: T::ZERO,
+            boundary: T::MAX,
         }
     }
 }
 
-impl fmt::Display for Bint {
+impl<T: Unsigned> fmt::Display for Bint<T> {
+    /// Writes the value. The alternate form, `{:#}`, writes `value/boundary`, which can be
+    /// parsed back with `str::parse`. That includes a zero boundary, which only [`Bint::new`]
+    /// can build: it's written as `0/0`, and parses back to `Bint::new(0)`.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let b: Bint = Bint::new_with_value(6, 3);
+    ///
+    /// assert_eq!("3", format!("{}", b));
+    /// assert_eq!("3/6", format!("{:#}", b));
+    /// assert_eq!(Ok(b), "3/6".parse());
+    ///
+    /// assert_eq!("0/0", format!("{:#}", Bint::<u8>::new(0)));
+    /// assert_eq!(Ok(Bint::<u8>::new(0)), "0/0".parse());
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.value)
+        if f.alternate() {
+            write!(f, "{}/{}", self.value, self.boundary)
+        } else {
+            write!(f, "{}", self.value)
+        }
     }
 }
 
-impl From<BintCell> for Bint {
+impl<T: Unsigned> From<BintCell<T>> for Bint<T> {
     /// ```
     /// use bint::{Bint, BintCell};
     ///
-    /// let cell = BintCell::new_with_value(8, 3);
-    /// let expected = Bint {
-    ///     value: cell.value(),
-    ///     boundary: cell.boundary,
-    /// };
+    /// let cell: BintCell = BintCell::new_with_value(8, 3);
+    /// let expected = Bint::new_with_value(cell.boundary(), cell.value());
     ///
     /// assert_eq!(expected, Bint::from(cell));
     /// ```
-    fn from(cell: BintCell) -> Self {
+    fn from(cell: BintCell<T>) -> Self {
         Bint {
             value: cell.value(),
             boundary: cell.boundary,
@@ -224,19 +628,16 @@ impl From<BintCell> for Bint {
     }
 }
 
-impl From<&BintCell> for Bint {
+impl<T: Unsigned> From<&BintCell<T>> for Bint<T> {
     /// ```
     /// use bint::{Bint, BintCell};
     ///
-    /// let cell = BintCell::new_with_value(8, 3);
-    /// let expected = Bint {
-    ///     value: cell.value(),
-    ///     boundary: cell.boundary,
-    /// };
+    /// let cell: BintCell = BintCell::new_with_value(8, 3);
+    /// let expected = Bint::new_with_value(cell.boundary(), cell.value());
     ///
     /// assert_eq!(expected, Bint::from(cell));
     /// ```
-    fn from(cell: &BintCell) -> Self {
+    fn from(cell: &BintCell<T>) -> Self {
         Bint {
             value: cell.value(),
             boundary: cell.boundary,
@@ -244,44 +645,47 @@ impl From<&BintCell> for Bint {
     }
 }
 
-impl From<DrainableBintCell> for Bint {
+impl<T: Unsigned> From<DrainableBintCell<T>> for Bint<T> {
     /// ```
     /// use bint::{Bint, DrainableBintCell};
     ///
-    /// let bint_cell = DrainableBintCell::new_with_value(8, 8, 3);
+    /// let bint_cell: DrainableBintCell = DrainableBintCell::new_with_value(8, 8, 3);
     /// let expected = Bint::new_with_value(8, 3);
     ///
     /// assert_eq!(expected, Bint::from(bint_cell));
     /// ```
-    fn from(cell: DrainableBintCell) -> Self {
+    fn from(cell: DrainableBintCell<T>) -> Self {
         Bint::from(cell.bint_cell)
     }
 }
 
-impl From<&DrainableBintCell> for Bint {
+impl<T: Unsigned> From<&DrainableBintCell<T>> for Bint<T> {
     /// ```
     /// use bint::{Bint, DrainableBintCell};
     ///
-    /// let bint_cell = DrainableBintCell::new_with_value(8, 8, 3);
+    /// let bint_cell: DrainableBintCell = DrainableBintCell::new_with_value(8, 8, 3);
     /// let expected = Bint::new_with_value(8, 3);
     ///
     /// assert_eq!(expected, Bint::from(&bint_cell));
     /// ```
-    fn from(cell: &DrainableBintCell) -> Self {
+    fn from(cell: &DrainableBintCell<T>) -> Self {
         Bint::from(cell.bint_cell.clone())
     }
 }
 
 /// `BintCell`: A bounded integer captured in a [`Cell`](https://doc.rust-lang.org/std/cell/struct.Cell.html).
 ///
-/// Allows for Bint functionality in a single entity.
+/// Allows for Bint functionality in a single entity. It has no event hooks; wrap it in an
+/// [`ObservedBintCell`](event::ObservedBintCell) to be told when it changes or wraps. Events
+/// only fire for steps taken through the wrapper, which forwards every method that moves the
+/// cell.
 ///
 /// Usage:
 ///
 /// ```
 /// use bint::BintCell;
 ///
-/// let b = BintCell::new(6);
+/// let b: BintCell = BintCell::new(6);
 ///
 /// b.down();
 /// assert_eq!(5, b.value());
@@ -292,23 +696,23 @@ impl From<&DrainableBintCell> for Bint {
 /// assert_eq!(2, b.value());
 /// ```
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
-pub struct BintCell {
-    pub cell: Cell<u8>,
-    pub boundary: u8,
+pub struct BintCell<T: Unsigned = u8> {
+    cell: Cell<T>,
+    boundary: T,
 }
 
-impl BintCell {
+impl<T: Unsigned> BintCell<T> {
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new(6);
+    /// let b: BintCell = BintCell::new(6);
     /// assert_eq!(0, b.value());
-    /// assert_eq!(6, b.boundary);
+    /// assert_eq!(6, b.boundary());
     /// ```
     #[must_use]
-    pub fn new(boundary: u8) -> BintCell {
+    pub fn new(boundary: T) -> BintCell<T> {
         BintCell {
-            cell: Cell::new(0),
+            cell: Cell::new(T::ZERO),
             boundary,
         }
     }
@@ -316,16 +720,16 @@ impl BintCell {
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new_with_value(6, 6);
+    /// let b: BintCell = BintCell::new_with_value(6, 6);
     /// assert_eq!(0, b.value());
-    /// assert_eq!(6, b.boundary);
+    /// assert_eq!(6, b.boundary());
     ///
-    /// let b = BintCell::new_with_value(6, 3);
+    /// let b: BintCell = BintCell::new_with_value(6, 3);
     /// assert_eq!(3, b.value());
-    /// assert_eq!(6, b.boundary);
+    /// assert_eq!(6, b.boundary());
     /// ```
     #[must_use]
-    pub fn new_with_value(boundary: u8, value: u8) -> BintCell {
+    pub fn new_with_value(boundary: T, value: T) -> BintCell<T> {
         if value >= boundary {
             BintCell::new(boundary)
         } else {
@@ -336,10 +740,45 @@ impl BintCell {
         }
     }
 
+    /// Unlike `new_with_value`, rejects a value that's out of range instead of resetting it
+    /// to 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the boundary is 0, and
+    /// `BintError::ValueOutOfRange` if the value isn't below the boundary.
+    ///
+    /// ```
+    /// use bint::{BintCell, BintError};
+    ///
+    /// let b: BintCell = BintCell::try_new(6, 3).unwrap();
+    /// assert_eq!(3, b.value());
+    ///
+    /// assert_eq!(Err(BintError::ZeroBoundary), BintCell::<u8>::try_new(0, 0));
+    /// assert!(BintCell::<u8>::try_new(6, 6).is_err());
+    /// ```
+    pub fn try_new(boundary: T, value: T) -> Result<BintCell<T>, BintError> {
+        Bint::try_new(boundary, value).map(BintCell::from)
+    }
+
+    /// Skips the range check done by the other constructors. See [`Bint::new_unchecked`].
+    #[must_use]
+    pub fn new_unchecked(boundary: T, value: T) -> BintCell<T> {
+        BintCell {
+            cell: Cell::new(value),
+            boundary,
+        }
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.boundary
+    }
+
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new(6);
+    /// let b: BintCell = BintCell::new(6);
     ///
     /// b.up();
     /// assert_eq!(2, b.up());
@@ -347,7 +786,7 @@ impl BintCell {
     /// b.up();
     /// assert_eq!(4, b.up());
     /// ```
-    pub fn up(&self) -> u8 {
+    pub fn up(&self) -> T {
         let bint = Bint {
             value: self.value(),
             boundary: self.boundary,
@@ -360,22 +799,23 @@ impl BintCell {
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new(6);
+    /// let b: BintCell = BintCell::new(6);
     ///
     /// b.up_x(3);
     /// assert_eq!(3, b.value());
+    ///
+    /// assert_eq!(1, b.up_x(1_000_000_000));
     /// ```
-    pub fn up_x(&self, x: u8) -> u8 {
-        for _ in 0..x {
-            self.up();
-        }
-        self.value()
+    pub fn up_x(&self, x: u64) -> T {
+        let bint = Bint::from(self).up_x(x);
+        self.cell.set(bint.value);
+        bint.value
     }
 
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new(6);
+    /// let b: BintCell = BintCell::new(6);
     ///
     /// b.down();
     /// assert_eq!(4, b.down());
@@ -383,7 +823,7 @@ impl BintCell {
     /// b.down();
     /// assert_eq!(2, b.down());
     /// ```
-    pub fn down(&self) -> u8 {
+    pub fn down(&self) -> T {
         let bint = Bint {
             value: self.value(),
             boundary: self.boundary,
@@ -396,98 +836,310 @@ impl BintCell {
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new(6);
+    /// let b: BintCell = BintCell::new(6);
     ///
     /// assert_eq!(4, b.down_x(2));
+    /// assert_eq!(0, b.down_x(1_000_000_000));
     /// ```
-    pub fn down_x(&self, x: u8) -> u8 {
-        for _ in 0..x {
-            self.down();
-        }
-        self.value()
+    pub fn down_x(&self, x: u64) -> T {
+        let bint = Bint::from(self).down_x(x);
+        self.cell.set(bint.value);
+        bint.value
     }
 
+    /// Moves up for positive values of x and down for negative ones.
+    ///
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new_with_value(8, 5);
-    /// b.reset();
+    /// let b: BintCell = BintCell::new(6);
     ///
-    /// assert_eq!(0, b.value());
+    /// assert_eq!(3, b.step(3));
+    /// assert_eq!(5, b.step(-4));
     /// ```
-    pub fn reset(&self) {
-        self.set(0);
+    pub fn step(&self, x: i64) -> T {
+        let bint = Bint::from(self).step(x);
+        self.cell.set(bint.value);
+        bint.value
     }
 
+    /// Moves up x spots, also returning how many times the value wrapped to 0.
+    ///
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::new(8);
-    /// b.set(5);
+    /// let button: BintCell = BintCell::new_with_value(6, 4);
     ///
-    /// assert_eq!(5, b.value());
+    /// assert_eq!((5, 0), button.up_with_carry(1));
+    /// assert_eq!((0, 1), button.up_with_carry(1));
+    /// assert_eq!((0, 2), button.up_with_carry(12));
     /// ```
-    pub fn set(&self, value: u8) {
-        self.cell.set(value);
+    pub fn up_with_carry(&self, x: u64) -> (T, u64) {
+        let (bint, laps) = Bint::from(self).up_with_carry(x);
+        self.cell.set(bint.value);
+        (bint.value, laps)
     }
 
-    /// Returns a Bint version x number of spots up. This is a utility method to simplify
-    /// something that I needed on my poker library.
+    /// Moves down x spots, also returning how many times the value wrapped below 0.
     ///
     /// ```
-    /// use bint::{Bint, BintCell};
+    /// use bint::BintCell;
     ///
-    /// let cell = BintCell::new_with_value(6, 3);
-    /// let expected = Bint {
-    ///     value: 0,
-    ///     boundary: 6
-    /// };
+    /// let button: BintCell = BintCell::new_with_value(6, 1);
     ///
-    /// assert_eq!(expected, cell.static_down_x(3));
-    /// assert_eq!(expected, cell.static_down_x(9));
+    /// assert_eq!((0, 0), button.down_with_carry(1));
+    /// assert_eq!((5, 1), button.down_with_carry(1));
     /// ```
-    pub fn static_down_x(&self, x: u8) -> Bint {
-        Bint::from(self).down_x(x)
+    pub fn down_with_carry(&self, x: u64) -> (T, u64) {
+        let (bint, laps) = Bint::from(self).down_with_carry(x);
+        self.cell.set(bint.value);
+        (bint.value, laps)
     }
 
-    /// Returns a Bint version x number of spots up. This is a utility method to simplify
-    /// something that I needed on my poker library.
+    /// Moves up for positive values of x and down for negative ones, also returning the
+    /// number of wraps. Wraps below 0 are counted as negative.
     ///
     /// ```
-    /// use bint::{Bint, BintCell};
-    ///
-    /// let cell = BintCell::new(6);
-    /// let expected = Bint {
-    ///     value: 3,
-    ///     boundary: 6
-    /// };
+    /// use bint::BintCell;
     ///
-    /// let actual = cell.static_up_x(3);
+    /// let button: BintCell = BintCell::new(6);
     ///
-    /// assert_eq!(expected, cell.static_up_x(3));
-    /// assert_eq!(expected, cell.static_up_x(9));
+    /// assert_eq!((5, -1), button.step_with_carry(-1));
+    /// assert_eq!((1, 1), button.step_with_carry(2));
     /// ```
-    pub fn static_up_x(&self, x: u8) -> Bint {
-        Bint::from(self).up_x(x)
+    pub fn step_with_carry(&self, x: i64) -> (T, i64) {
+        let (bint, laps) = Bint::from(self).step_with_carry(x);
+        self.cell.set(bint.value);
+        (bint.value, laps)
     }
 
-    #[must_use]
-    pub fn value(&self) -> u8 {
-        self.cell.get()
+    /// Moves up to the next value the predicate accepts, checking each value at most once and
+    /// the current one last. Returns `None`, leaving the value unchanged, if no value is
+    /// accepted.
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let folded = [false, true, true, false];
+    /// let seat: BintCell = BintCell::new(4);
+    ///
+    /// assert_eq!(Some(3), seat.up_until(|s| !folded[usize::from(s)]));
+    /// assert_eq!(Some(0), seat.up_until(|s| !folded[usize::from(s)]));
+    /// assert_eq!(None, seat.up_until(|_| false));
+    /// assert_eq!(0, seat.value());
+    /// ```
+    pub fn up_until<P: FnMut(T) -> bool>(&self, predicate: P) -> Option<T> {
+        let bint = Bint::from(self).up_until(predicate)?;
+        self.cell.set(bint.value);
+        Some(bint.value)
     }
-}
 
-impl Default for BintCell {
-    /// Defaults to the maximum value of an unsigned 8 integer.
+    /// Moves down to the next value the predicate accepts, checking each value at most once and
+    /// the current one last. Returns `None`, leaving the value unchanged, if no value is
+    /// accepted.
     ///
     /// ```
     /// use bint::BintCell;
     ///
-    /// let b = BintCell::default();
+    /// let seat: BintCell = BintCell::new(4);
     ///
-    /// for _ in 0..u8::MAX {
-    ///     b.up();
-    /// }
+    /// assert_eq!(Some(2), seat.down_until(|s| s % 2 == 0));
+    /// assert_eq!(Some(0), seat.down_until(|s| s % 2 == 0));
+    /// ```
+    pub fn down_until<P: FnMut(T) -> bool>(&self, predicate: P) -> Option<T> {
+        let bint = Bint::from(self).down_until(predicate)?;
+        self.cell.set(bint.value);
+        Some(bint.value)
+    }
+
+    /// Grows the boundary by one to make room for a new member at `position`, returning the
+    /// value moved so that it still refers to the same member. See [`Bint::insert_at`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::Overflow` if the boundary is already the integer type's maximum, and
+    /// `BintError::ValueOutOfRange` if the position is beyond the new boundary.
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let mut seat: BintCell = BintCell::new_with_value(4, 2);
+    ///
+    /// assert_eq!(Ok(3), seat.insert_at(0));
+    /// assert_eq!(5, seat.boundary());
+    /// ```
+    pub fn insert_at(&mut self, position: T) -> Result<T, BintError> {
+        let bint = Bint::from(&*self).insert_at(position)?;
+        self.boundary = bint.boundary;
+        self.cell.set(bint.value);
+        Ok(bint.value)
+    }
+
+    /// Shrinks the boundary by one, removing the member at `position`, and returns the value
+    /// moved so that it still refers to the same member, or the next one if the current member
+    /// was removed. See [`Bint::remove_at`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if the position isn't below the boundary.
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let mut seat: BintCell = BintCell::new_with_value(4, 3);
+    ///
+    /// assert_eq!(Ok(0), seat.remove_at(3));
+    /// assert_eq!(3, seat.boundary());
+    /// ```
+    pub fn remove_at(&mut self, position: T) -> Result<T, BintError> {
+        let bint = Bint::from(&*self).remove_at(position)?;
+        self.boundary = bint.boundary;
+        self.cell.set(bint.value);
+        Ok(bint.value)
+    }
+
+    /// The number of steps up from this cell's value to the other one's, or `None` if their
+    /// boundaries differ.
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let a: BintCell = BintCell::new_with_value(6, 4);
+    /// let b: BintCell = BintCell::new_with_value(6, 1);
+    ///
+    /// assert_eq!(Some(3), a.distance_up(&b));
+    /// assert_eq!(Some(3), a.distance_down(&b));
+    /// assert_eq!(Some(-3), a.signed_distance(&b));
+    /// ```
+    #[must_use]
+    pub fn distance_up(&self, other: &BintCell<T>) -> Option<T> {
+        Bint::from(self).distance_up(Bint::from(other))
+    }
+
+    /// The number of steps down from this cell's value to the other one's, or `None` if their
+    /// boundaries differ.
+    #[must_use]
+    pub fn distance_down(&self, other: &BintCell<T>) -> Option<T> {
+        Bint::from(self).distance_down(Bint::from(other))
+    }
+
+    /// The shortest number of steps from this cell's value to the other one's, or `None` if
+    /// their boundaries differ. See [`Bint::signed_distance`].
+    #[must_use]
+    pub fn signed_distance(&self, other: &BintCell<T>) -> Option<i128> {
+        Bint::from(self).signed_distance(Bint::from(other))
+    }
+
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let b: BintCell = BintCell::new_with_value(8, 5);
+    /// b.reset();
+    ///
+    /// assert_eq!(0, b.value());
+    /// ```
+    pub fn reset(&self) {
+        self.set(T::ZERO);
+    }
+
+    /// Like `new_with_value`, a value that's out of range resets the cell to 0.
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let b: BintCell = BintCell::new(8);
+    ///
+    /// b.set(5);
+    /// assert_eq!(5, b.value());
+    ///
+    /// b.set(8);
+    /// assert_eq!(0, b.value());
+    /// ```
+    pub fn set(&self, value: T) {
+        self.cell
+            .set(Bint::new_with_value(self.boundary, value).value);
+    }
+
+    /// Leaves the cell untouched when the value is out of range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the boundary is 0, and
+    /// `BintError::ValueOutOfRange` if the value isn't below the boundary.
+    ///
+    /// ```
+    /// use bint::{BintCell, BintError};
+    ///
+    /// let b: BintCell = BintCell::new_with_value(8, 3);
+    ///
+    /// assert_eq!(Ok(()), b.try_set(5));
+    /// assert_eq!(
+    ///     Err(BintError::ValueOutOfRange { value: 8, boundary: 8 }),
+    ///     b.try_set(8)
+    /// );
+    /// assert_eq!(5, b.value());
+    /// ```
+    pub fn try_set(&self, value: T) -> Result<(), BintError> {
+        self.cell.set(Bint::try_new(self.boundary, value)?.value);
+        Ok(())
+    }
+
+    /// Skips the range check done by `set`. See [`Bint::new_unchecked`].
+    pub fn set_unchecked(&self, value: T) {
+        self.cell.set(value);
+    }
+
+    /// Returns a Bint version x number of spots up. This is a utility method to simplify
+    /// something that I needed on my poker library.
+    ///
+    /// ```
+    /// use bint::{Bint, BintCell};
+    ///
+    /// let cell: BintCell = BintCell::new_with_value(6, 3);
+    /// let expected = Bint::new_with_value(6, 0);
+    ///
+    /// assert_eq!(expected, cell.static_down_x(3));
+    /// assert_eq!(expected, cell.static_down_x(9));
+    /// ```
+    pub fn static_down_x(&self, x: u64) -> Bint<T> {
+        Bint::from(self).down_x(x)
+    }
+
+    /// Returns a Bint version x number of spots up. This is a utility method to simplify
+    /// something that I needed on my poker library.
+    ///
+    /// ```
+    /// use bint::{Bint, BintCell};
+    ///
+    /// let cell: BintCell = BintCell::new(6);
+    /// let expected = Bint::new_with_value(6, 3);
+    ///
+    /// let actual = cell.static_up_x(3);
+    ///
+    /// assert_eq!(expected, cell.static_up_x(3));
+    /// assert_eq!(expected, cell.static_up_x(9));
+    /// ```
+    pub fn static_up_x(&self, x: u64) -> Bint<T> {
+        Bint::from(self).up_x(x)
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.cell.get()
+    }
+}
+
+impl<T: Unsigned> Default for BintCell<T> {
+    /// Defaults to a boundary of the maximum value of the integer type.
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let b: BintCell = BintCell::default();
+    ///
+    /// for _ in 0..u8::MAX {
+    ///     b.up();
+    /// }
     ///
     /// for _ in 0..u8::MAX {
     ///     b.down();
@@ -497,95 +1149,165 @@ impl Default for BintCell {
     /// ```
     fn default() -> Self {
         BintCell {
-            cell: Cell::new(0),
-            boundary: u8::MAX,
+            cell: Cell::new(T::ZERO),
+            boundary: T::MAX,
         }
     }
 }
 
-impl fmt::Display for BintCell {
+impl<T: Unsigned> fmt::Display for BintCell<T> {
+    /// Writes the value, or `value/boundary` in the alternate form, like [`Bint`].
+    ///
+    /// ```
+    /// use bint::BintCell;
+    ///
+    /// let b: BintCell = BintCell::new_with_value(6, 3);
+    ///
+    /// assert_eq!("3", b.to_string());
+    /// assert_eq!("3/6", format!("{:#}", b));
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.value())
+        fmt::Display::fmt(&Bint::from(self), f)
     }
 }
 
-impl From<Bint> for BintCell {
+impl<T: Unsigned> From<Bint<T>> for BintCell<T> {
     /// ```
     /// use bint::{Bint, BintCell};
     ///
-    /// let bint = Bint::new_with_value(8, 3);
+    /// let bint: Bint = Bint::new_with_value(8, 3);
     /// let expected = BintCell::new_with_value(8, 3);
     ///
     /// assert_eq!(expected, BintCell::from(bint));
     /// ```
-    fn from(cell: Bint) -> Self {
+    fn from(cell: Bint<T>) -> Self {
         BintCell::new_with_value(cell.boundary, cell.value)
     }
 }
 
-impl From<&Bint> for BintCell {
+impl<T: Unsigned> From<&Bint<T>> for BintCell<T> {
     /// ```
     /// use bint::{Bint, BintCell};
     ///
-    /// let bint = Bint::new_with_value(8, 3);
+    /// let bint: Bint = Bint::new_with_value(8, 3);
     /// let expected = BintCell::new_with_value(8, 3);
     ///
     /// assert_eq!(expected, BintCell::from(&bint));
     /// ```
-    fn from(cell: &Bint) -> Self {
+    fn from(cell: &Bint<T>) -> Self {
         BintCell::new_with_value(cell.boundary, cell.value)
     }
 }
 
-impl From<DrainableBintCell> for BintCell {
+impl<T: Unsigned> From<DrainableBintCell<T>> for BintCell<T> {
     /// ```
     /// use bint::{BintCell, DrainableBintCell};
     ///
-    /// let bint_cell = DrainableBintCell::new_with_value(8, 8, 3);
+    /// let bint_cell: DrainableBintCell = DrainableBintCell::new_with_value(8, 8, 3);
     /// let expected = BintCell::new_with_value(8, 3);
     ///
     /// assert_eq!(expected, BintCell::from(bint_cell));
     /// ```
-    fn from(cell: DrainableBintCell) -> Self {
+    fn from(cell: DrainableBintCell<T>) -> Self {
         cell.bint_cell
     }
 }
 
-impl From<&DrainableBintCell> for BintCell {
+impl<T: Unsigned> From<&DrainableBintCell<T>> for BintCell<T> {
     /// ```
     /// use bint::{BintCell, DrainableBintCell};
     ///
-    /// let bint_cell = DrainableBintCell::new_with_value(8, 8, 3);
+    /// let bint_cell: DrainableBintCell = DrainableBintCell::new_with_value(8, 8, 3);
     /// let expected = BintCell::new_with_value(8, 3);
     ///
     /// assert_eq!(expected, BintCell::from(&bint_cell));
     /// ```
-    fn from(cell: &DrainableBintCell) -> Self {
+    fn from(cell: &DrainableBintCell<T>) -> Self {
         cell.bint_cell.clone()
     }
 }
 
 /// Version of a `BintCell` that can only be called a limited number of times, after which it
 /// returns none.
+///
+/// The capacity it started with is kept, so it can be refilled or reset, and an optional
+/// maximum caps how far it can be refilled.
+///
+/// It has no event hooks. To be told when it wraps or runs out of capacity, wrap it in an
+/// [`ObservedDrainableBintCell`](event::ObservedDrainableBintCell). Events only fire for steps
+/// taken through the wrapper, which forwards every method that moves the cell or drains it.
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
-pub struct DrainableBintCell {
-    bint_cell: BintCell,
-    pub capacity: Cell<usize>,
+pub struct DrainableBintCell<T: Unsigned = u8> {
+    bint_cell: BintCell<T>,
+    capacity: Cell<usize>,
+    original_capacity: Cell<usize>,
+    max_capacity: Option<usize>,
 }
 
-impl DrainableBintCell {
+impl<T: Unsigned> DrainableBintCell<T> {
+    #[must_use]
+    pub fn new(boundary: T, capacity: usize) -> DrainableBintCell<T> {
+        DrainableBintCell::from_parts(BintCell::new(boundary), capacity, None)
+    }
+
+    /// Creates a `DrainableBintCell` that can't be refilled past the maximum. A capacity above
+    /// the maximum is lowered to it.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new_with_max_capacity(6, 3, 5);
+    ///
+    /// assert_eq!(Some(5), b.max_capacity());
+    /// assert_eq!(5, b.refill(10));
+    /// assert_eq!(5, DrainableBintCell::<u8>::new_with_max_capacity(6, 8, 5).remaining());
+    /// ```
     #[must_use]
-    pub fn new(boundary: u8, capacity: usize) -> DrainableBintCell {
+    pub fn new_with_max_capacity(
+        boundary: T,
+        capacity: usize,
+        max_capacity: usize,
+    ) -> DrainableBintCell<T> {
+        DrainableBintCell::from_parts(BintCell::new(boundary), capacity, Some(max_capacity))
+    }
+
+    pub(crate) fn from_parts(
+        bint_cell: BintCell<T>,
+        capacity: usize,
+        max_capacity: Option<usize>,
+    ) -> DrainableBintCell<T> {
+        let capacity = max_capacity.map_or(capacity, |max| capacity.min(max));
         DrainableBintCell {
-            bint_cell: BintCell::new(boundary),
+            bint_cell,
             capacity: Cell::new(capacity),
+            original_capacity: Cell::new(capacity),
+            max_capacity,
+        }
+    }
+
+    /// Like `from_parts`, but with the remaining capacity apart from the original one, and
+    /// rejecting either being above the maximum instead of lowering it.
+    pub(crate) fn try_from_parts(
+        bint_cell: BintCell<T>,
+        remaining: usize,
+        original_capacity: usize,
+        max_capacity: Option<usize>,
+    ) -> Result<DrainableBintCell<T>, BintError> {
+        if let Some(max) = max_capacity {
+            let capacity = remaining.max(original_capacity);
+            if capacity > max {
+                return Err(BintError::CapacityAboveMax { capacity, max });
+            }
         }
+        let cell = DrainableBintCell::from_parts(bint_cell, original_capacity, max_capacity);
+        cell.capacity.set(remaining);
+        Ok(cell)
     }
 
     /// ```
     /// use bint::DrainableBintCell;
     ///
-    /// let b = DrainableBintCell::new_with_value(4, 4, 3);
+    /// let b: DrainableBintCell = DrainableBintCell::new_with_value(4, 4, 3);
     ///
     /// assert_eq!(3, b.value());
     /// assert_eq!(2, b.down().unwrap());
@@ -595,17 +1317,56 @@ impl DrainableBintCell {
     /// assert!(b.down().is_none());
     /// ```
     #[must_use]
-    pub fn new_with_value(boundary: u8, capacity: usize, value: u8) -> DrainableBintCell {
-        DrainableBintCell {
-            bint_cell: BintCell::new_with_value(boundary, value),
-            capacity: Cell::new(capacity),
-        }
+    pub fn new_with_value(boundary: T, capacity: usize, value: T) -> DrainableBintCell<T> {
+        DrainableBintCell::from_parts(BintCell::new_with_value(boundary, value), capacity, None)
+    }
+
+    /// Unlike `new_with_value`, rejects a value that's out of range instead of resetting it
+    /// to 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the boundary is 0, and
+    /// `BintError::ValueOutOfRange` if the value isn't below the boundary.
+    ///
+    /// ```
+    /// use bint::{BintError, DrainableBintCell};
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::try_new(4, 4, 3).unwrap();
+    /// assert_eq!(0, b.up().unwrap());
+    ///
+    /// assert_eq!(
+    ///     Err(BintError::ValueOutOfRange { value: 4, boundary: 4 }),
+    ///     DrainableBintCell::<u8>::try_new(4, 4, 4)
+    /// );
+    /// ```
+    pub fn try_new(
+        boundary: T,
+        capacity: usize,
+        value: T,
+    ) -> Result<DrainableBintCell<T>, BintError> {
+        Ok(DrainableBintCell::from_parts(
+            BintCell::try_new(boundary, value)?,
+            capacity,
+            None,
+        ))
+    }
+
+    /// Skips the range check done by the other constructors. See [`Bint::new_unchecked`].
+    #[must_use]
+    pub fn new_unchecked(boundary: T, capacity: usize, value: T) -> DrainableBintCell<T> {
+        DrainableBintCell::from_parts(BintCell::new_unchecked(boundary, value), capacity, None)
+    }
+
+    #[must_use]
+    pub fn boundary(&self) -> T {
+        self.bint_cell.boundary()
     }
 
     /// ```
     /// use bint::DrainableBintCell;
     ///
-    /// let b = DrainableBintCell::new(4, 8);
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 8);
     ///
     /// assert_eq!(3, b.down().unwrap());
     /// assert_eq!(2, b.down().unwrap());
@@ -618,7 +1379,7 @@ impl DrainableBintCell {
     /// assert!(b.down().is_none());
     /// ```
     #[must_use]
-    pub fn down(&self) -> Option<u8> {
+    pub fn down(&self) -> Option<T> {
         self.drain()?;
         Some(self.bint_cell.down())
     }
@@ -626,18 +1387,121 @@ impl DrainableBintCell {
     /// ```
     /// use bint::DrainableBintCell;
     ///
-    /// let b = DrainableBintCell::new(4, 4);
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 4);
     ///
     /// assert_eq!(2, b.down_x(2).unwrap());
     /// assert_eq!(0, b.down_x(2).unwrap());
     /// assert!(b.down_x(2).is_none());
     /// ```
+    ///
+    /// When there isn't enough capacity left, it moves down as far as the capacity allows
+    /// before returning none:
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 4);
+    ///
+    /// assert!(b.down_x(5).is_none());
+    /// assert_eq!(2, b.value());
+    /// ```
+    #[must_use]
+    pub fn down_x(&self, x: u64) -> Option<T> {
+        let drained = self.drain_x(x);
+        self.bint_cell.down_x(drained);
+        if drained == x {
+            Some(self.value())
+        } else {
+            None
+        }
+    }
+
+    /// Moves up for positive values of x and down for negative ones, draining the capacity
+    /// like `up_x` and `down_x`.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 4);
+    ///
+    /// assert_eq!(3, b.step(3).unwrap());
+    /// assert_eq!(2, b.step(-1).unwrap());
+    /// assert!(b.step(-1).is_none());
+    /// ```
+    #[must_use]
+    pub fn step(&self, x: i64) -> Option<T> {
+        if x < 0 {
+            self.down_x(x.unsigned_abs())
+        } else {
+            self.up_x(x.unsigned_abs())
+        }
+    }
+
+    /// Moves up x spots like `up_x`, also returning how many times the value wrapped to 0.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 8);
+    ///
+    /// assert_eq!((3, 0), b.up_with_carry(3).unwrap());
+    /// assert_eq!((0, 2), b.up_with_carry(5).unwrap());
+    /// assert!(b.up_with_carry(1).is_none());
+    /// ```
+    #[must_use]
+    pub fn up_with_carry(&self, x: u64) -> Option<(T, u64)> {
+        let drained = self.drain_x(x);
+        let carried = self.bint_cell.up_with_carry(drained);
+        if drained == x {
+            Some(carried)
+        } else {
+            None
+        }
+    }
+
+    /// Moves down x spots like `down_x`, also returning how many times the value wrapped
+    /// below 0.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 8);
+    ///
+    /// assert_eq!((3, 1), b.down_with_carry(1).unwrap());
+    /// assert_eq!((3, 1), b.down_with_carry(4).unwrap());
+    /// assert!(b.down_with_carry(4).is_none());
+    /// ```
+    #[must_use]
+    pub fn down_with_carry(&self, x: u64) -> Option<(T, u64)> {
+        let drained = self.drain_x(x);
+        let carried = self.bint_cell.down_with_carry(drained);
+        if drained == x {
+            Some(carried)
+        } else {
+            None
+        }
+    }
+
+    /// Moves up for positive values of x and down for negative ones like `step`, also
+    /// returning the number of wraps. Wraps below 0 are counted as negative.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 8);
+    ///
+    /// assert_eq!((3, -1), b.step_with_carry(-1).unwrap());
+    /// assert_eq!((1, 1), b.step_with_carry(2).unwrap());
+    /// ```
     #[must_use]
-    pub fn down_x(&self, x: u8) -> Option<u8> {
-        for _ in 0..x {
-            self.down()?;
+    pub fn step_with_carry(&self, x: i64) -> Option<(T, i64)> {
+        if x < 0 {
+            let (value, laps) = self.down_with_carry(x.unsigned_abs())?;
+            Some((value, i64::try_from(laps).map_or(i64::MIN, |laps| -laps)))
+        } else {
+            let (value, laps) = self.up_with_carry(x.unsigned_abs())?;
+            Some((value, i64::try_from(laps).unwrap_or(i64::MAX)))
         }
-        Some(self.value())
     }
 
     /// Removes one from the capacity.
@@ -646,10 +1510,113 @@ impl DrainableBintCell {
         Some(self.capacity.get())
     }
 
+    /// Removes up to x from the capacity, returning how much was removed.
+    fn drain_x(&self, x: u64) -> u64 {
+        let capacity = self.capacity.get();
+        let drained = usize::try_from(x).map_or(capacity, |x| x.min(capacity));
+        self.capacity.set(capacity - drained);
+        u64::try_from(drained).unwrap_or(x)
+    }
+
+    /// How many more steps can be taken.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 8);
+    /// b.up_x(3).unwrap();
+    ///
+    /// assert_eq!(5, b.remaining());
+    /// assert_eq!(
+    ///     "used 3 of 8",
+    ///     format!("used {} of {}", b.used(), b.original_capacity())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.capacity.get()
+    }
+
+    /// The capacity it was created with, or last set to with `set_capacity`.
+    #[must_use]
+    pub fn original_capacity(&self) -> usize {
+        self.original_capacity.get()
+    }
+
+    /// How much of the original capacity has been drained. Refilling past the original
+    /// capacity doesn't make this negative; it's 0.
+    #[must_use]
+    pub fn used(&self) -> usize {
+        self.original_capacity().saturating_sub(self.remaining())
+    }
+
+    #[must_use]
+    pub fn max_capacity(&self) -> Option<usize> {
+        self.max_capacity
+    }
+
+    /// Adds n to the remaining capacity, up to the maximum capacity if there is one, and
+    /// returns the new remaining capacity.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 2);
+    /// b.up_x(2).unwrap();
+    /// assert!(b.up().is_none());
+    ///
+    /// assert_eq!(1, b.refill(1));
+    /// assert_eq!(Some(3), b.up());
+    /// ```
+    pub fn refill(&self, n: usize) -> usize {
+        self.capacity
+            .set(self.capped(self.remaining().saturating_add(n)));
+        self.remaining()
+    }
+
+    /// Sets the remaining capacity back to the original capacity.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 4);
+    /// b.down_x(4).unwrap();
+    /// b.reset_capacity();
+    ///
+    /// assert_eq!(4, b.remaining());
+    /// assert_eq!(0, b.used());
+    /// ```
+    pub fn reset_capacity(&self) {
+        self.capacity.set(self.original_capacity());
+    }
+
+    /// Replaces both the remaining and the original capacity, as if it had been created with
+    /// the new capacity. A capacity above the maximum is lowered to it.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 4);
+    /// b.up().unwrap();
+    /// b.set_capacity(10);
+    ///
+    /// assert_eq!(10, b.remaining());
+    /// assert_eq!(10, b.original_capacity());
+    /// ```
+    pub fn set_capacity(&self, capacity: usize) {
+        let capacity = self.capped(capacity);
+        self.capacity.set(capacity);
+        self.original_capacity.set(capacity);
+    }
+
+    fn capped(&self, capacity: usize) -> usize {
+        self.max_capacity.map_or(capacity, |max| capacity.min(max))
+    }
+
     /// ```
     /// use bint::DrainableBintCell;
     ///
-    /// let b = DrainableBintCell::new(1, 1);
+    /// let b: DrainableBintCell = DrainableBintCell::new(1, 1);
     ///
     /// assert!(b.has_capacity());
     /// assert_eq!(0, b.up().unwrap());
@@ -663,7 +1630,7 @@ impl DrainableBintCell {
     /// ```
     /// use bint::DrainableBintCell;
     ///
-    /// let b = DrainableBintCell::new(4, 4);
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 4);
     ///
     /// assert_eq!(1, b.up().unwrap());
     /// assert_eq!(2, b.up().unwrap());
@@ -672,7 +1639,7 @@ impl DrainableBintCell {
     /// assert!(b.down().is_none());
     /// ```
     #[must_use]
-    pub fn up(&self) -> Option<u8> {
+    pub fn up(&self) -> Option<T> {
         self.drain()?;
         Some(self.bint_cell.up())
     }
@@ -680,34 +1647,72 @@ impl DrainableBintCell {
     /// ```
     /// use bint::DrainableBintCell;
     ///
-    /// let b = DrainableBintCell::new(4, 4);
+    /// let b: DrainableBintCell = DrainableBintCell::new(4, 4);
     ///
     /// assert_eq!(3, b.up_x(3).unwrap());
     /// assert_eq!(0, b.up_x(1).unwrap());
     /// assert!(b.up_x(2).is_none());
     /// ```
     #[must_use]
-    pub fn up_x(&self, x: u8) -> Option<u8> {
-        for _ in 0..x {
-            self.up()?;
+    pub fn up_x(&self, x: u64) -> Option<T> {
+        let drained = self.drain_x(x);
+        self.bint_cell.up_x(drained);
+        if drained == x {
+            Some(self.value())
+        } else {
+            None
         }
-        Some(self.value())
     }
 
     #[must_use]
-    pub fn value(&self) -> u8 {
+    pub fn value(&self) -> T {
         self.bint_cell.value()
     }
 }
 
+impl<T: Unsigned> fmt::Display for DrainableBintCell<T> {
+    /// Writes the value. The alternate form, `{:#}`, writes `value/boundary [cap capacity]`
+    /// with the remaining capacity, which can be parsed back with `str::parse`. The original
+    /// capacity follows it after a `/` when they differ, and the maximum capacity follows
+    /// after ` max ` when there is one.
+    ///
+    /// ```
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new_with_value(6, 4, 3);
+    ///
+    /// assert_eq!("3", b.to_string());
+    /// assert_eq!("3/6 [cap 4]", format!("{:#}", b));
+    ///
+    /// let capped: DrainableBintCell = DrainableBintCell::new_with_max_capacity(6, 4, 8);
+    /// capped.up_x(2).unwrap();
+    ///
+    /// assert_eq!("2/6 [cap 2/4 max 8]", format!("{:#}", capped));
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            return write!(f, "{}", self.value());
+        }
+        write!(f, "{:#} [cap {}", self.bint_cell, self.remaining())?;
+        if self.original_capacity() != self.remaining() {
+            write!(f, "/{}", self.original_capacity())?;
+        }
+        if let Some(max) = self.max_capacity {
+            write!(f, " max {}", max)?;
+        }
+        write!(f, "]")
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::*;
 
     #[test]
     fn new() {
         assert_eq!(
-            Bint::new(6),
+            Bint::<u8>::new(6),
             Bint {
                 value: 0,
                 boundary: 6
@@ -726,7 +1731,7 @@ mod tests {
 
     #[test]
     fn up() {
-        let mut b = Bint::new(8);
+        let mut b: Bint = Bint::new(8);
 
         for _ in 0..16 {
             b = b.up();
@@ -737,7 +1742,7 @@ mod tests {
 
     #[test]
     fn up_default_defect() {
-        let b = Bint::new(0);
+        let b: Bint = Bint::new(0);
 
         let c = b.up();
 
@@ -746,7 +1751,7 @@ mod tests {
 
     #[test]
     fn down() {
-        let mut b = Bint::new(8);
+        let mut b: Bint = Bint::new(8);
 
         for _ in 0..16 {
             b = b.down();
@@ -757,7 +1762,7 @@ mod tests {
 
     #[test]
     fn down_default_defect() {
-        let b = Bint::new(0);
+        let b: Bint = Bint::new(0);
 
         let c = b.down();
 
@@ -766,10 +1771,7 @@ mod tests {
 
     #[test]
     fn up_bint_outside() {
-        let b: Bint = Bint {
-            value: 50,
-            boundary: 10,
-        };
+        let b: Bint = Bint::new_with_value(10, 50);
         let b: Bint = b.up();
         assert_eq!(1, b.value);
 
@@ -778,6 +1780,155 @@ mod tests {
         assert_eq!(9, b.value);
     }
 
+    #[test]
+    fn up_wide() {
+        let b: Bint<u64> = Bint::new_with_value(u64::MAX, u64::MAX - 1);
+
+        assert_eq!(0, b.up().value);
+        assert_eq!(u64::MAX - 2, b.down().value);
+        assert_eq!(1, b.up_x(2).value);
+    }
+
+    #[test]
+    fn down_wide() {
+        let mut b: Bint<u128> = Bint::new(1_000);
+
+        for _ in 0..16 {
+            b = b.down();
+        }
+
+        assert_eq!(984, b.value);
+    }
+
+    #[test]
+    fn from_wide() {
+        let b: Bint<usize> = Bint::new_with_value(300, 299);
+        let cell = BintCell::from(b);
+        cell.up();
+
+        assert_eq!(Bint::new_with_value(300, 0), Bint::from(&cell));
+    }
+
+    #[test]
+    fn up_x_matches_up() {
+        for boundary in 0..8_u8 {
+            let mut stepped: Bint = Bint::new(boundary);
+            for x in 0..20_i64 {
+                assert_eq!(stepped, Bint::new(boundary).up_x(x.unsigned_abs()));
+                assert_eq!(stepped, Bint::new(boundary).step(x));
+                stepped = stepped.up();
+            }
+        }
+    }
+
+    #[test]
+    fn down_x_matches_down() {
+        for boundary in 0..8_u8 {
+            let mut stepped: Bint = Bint::new(boundary);
+            for x in 0..20_i64 {
+                assert_eq!(stepped, Bint::new(boundary).down_x(x.unsigned_abs()));
+                assert_eq!(stepped, Bint::new(boundary).step(-x));
+                stepped = stepped.down();
+            }
+        }
+    }
+
+    #[test]
+    fn cell_set_outside() {
+        let b: BintCell = BintCell::new_with_value(10, 7);
+
+        b.set(50);
+        assert_eq!(0, b.value());
+        assert_eq!(9, b.down());
+
+        let b: BintCell = BintCell::new(0);
+
+        b.set(5);
+        assert_eq!(0, b.up());
+        assert_eq!(Err(BintError::ZeroBoundary), b.try_set(0));
+    }
+
+    #[test]
+    fn up_max_value() {
+        let b: Bint = Bint::new_with_value(u8::MAX, u8::MAX);
+        assert_eq!(1, b.up().value());
+
+        let b: Bint = Bint::new_with_value(u8::MAX, u8::MAX - 1);
+        assert_eq!(0, b.up().value());
+    }
+
+    #[test]
+    fn up_x_wide() {
+        let b: Bint<u128> = Bint::new_with_value(u128::MAX, u128::MAX - 1);
+
+        let x = u128::from(u64::MAX);
+
+        assert_eq!(x - 1, b.up_x(u64::MAX).value);
+        assert_eq!(u128::MAX - 1 - x, b.down_x(u64::MAX).value);
+    }
+
+    #[test]
+    fn try_new_wide() {
+        assert_eq!(
+            Err(BintError::ValueOutOfRange {
+                value: u128::from(u64::MAX),
+                boundary: u128::from(u64::MAX)
+            }),
+            Bint::try_new(u64::MAX, u64::MAX)
+        );
+        assert_eq!(
+            Bint::new_with_value(u64::MAX, u64::MAX - 1),
+            Bint::try_new(u64::MAX, u64::MAX - 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn try_new_error_source() {
+        let error: Box<dyn std::error::Error> = Box::new(BintError::ZeroBoundary);
+
+        assert_eq!("boundary must be greater than 0", error.to_string());
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn carry_matches_up() {
+        for boundary in 1..8_u8 {
+            let mut stepped: Bint = Bint::new(boundary);
+            let mut laps = 0;
+            for x in 0..20_i64 {
+                assert_eq!((stepped, laps), Bint::new(boundary).step_with_carry(x));
+                stepped = stepped.up();
+                if stepped.value() == 0 {
+                    laps += 1;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn carry_matches_down() {
+        for boundary in 1..8_u8 {
+            let mut stepped: Bint = Bint::new(boundary);
+            let mut laps = 0;
+            for x in 0..20_i64 {
+                assert_eq!((stepped, laps), Bint::new(boundary).step_with_carry(-x));
+                if stepped.value() == 0 {
+                    laps -= 1;
+                }
+                stepped = stepped.down();
+            }
+        }
+    }
+
+    #[test]
+    fn carry_extremes() {
+        let b: Bint = Bint::new(1);
+
+        assert_eq!((b, i64::MIN), b.step_with_carry(i64::MIN));
+        assert_eq!((b, u64::MAX), b.up_with_carry(u64::MAX));
+        assert_eq!((Bint::new(0), 0), Bint::<u8>::new(0).up_with_carry(3));
+    }
+
     #[test]
     fn cell_format() {
         let b: BintCell = BintCell {
@@ -802,7 +1953,7 @@ mod tests {
 
     #[test]
     fn cell_up_loop() {
-        let b = BintCell::new(8);
+        let b: BintCell = BintCell::new(8);
 
         for _ in 0..16 {
             b.up();
@@ -826,7 +1977,7 @@ mod tests {
 
     #[test]
     fn cell_down_loop() {
-        let b = BintCell::new(8);
+        let b: BintCell = BintCell::new(8);
 
         for _ in 0..16 {
             b.down();
@@ -837,7 +1988,7 @@ mod tests {
 
     #[test]
     fn cell_reset() {
-        let b = BintCell::new(8);
+        let b: BintCell = BintCell::new(8);
         b.up();
         b.up();
         b.up();
@@ -849,7 +2000,7 @@ mod tests {
 
     #[test]
     fn drain_down() {
-        let b = DrainableBintCell::new(8, 8);
+        let b: DrainableBintCell = DrainableBintCell::new(8, 8);
 
         assert_eq!(7, b.down().unwrap());
         assert_eq!(6, b.down().unwrap());
@@ -862,9 +2013,28 @@ mod tests {
         assert!(b.up().is_none());
     }
 
+    #[test]
+    fn drain_wide() {
+        let b: DrainableBintCell<u32> = DrainableBintCell::new_with_value(100_000, 2, 99_999);
+
+        assert_eq!(0, b.up().unwrap());
+        assert_eq!(99_999, b.down().unwrap());
+        assert!(b.down().is_none());
+    }
+
+    #[test]
+    fn drain_up_x_partial() {
+        let b: DrainableBintCell = DrainableBintCell::new(8, 5);
+
+        assert!(b.up_x(u64::MAX).is_none());
+        assert_eq!(5, b.value());
+        assert_eq!(5, b.up_x(0).unwrap());
+        assert!(!b.has_capacity());
+    }
+
     #[test]
     fn drain_drain() {
-        let b = DrainableBintCell::new(8, 8);
+        let b: DrainableBintCell = DrainableBintCell::new(8, 8);
 
         assert_eq!(7, b.drain().unwrap());
         assert_eq!(6, b.drain().unwrap());
@@ -879,7 +2049,7 @@ mod tests {
 
     #[test]
     fn drain_up() {
-        let b = DrainableBintCell::new(8, 8);
+        let b: DrainableBintCell = DrainableBintCell::new(8, 8);
 
         assert_eq!(1, b.up().unwrap());
         assert_eq!(2, b.up().unwrap());
@@ -891,4 +2061,126 @@ mod tests {
         assert_eq!(0, b.up().unwrap());
         assert!(b.up().is_none());
     }
+
+    #[test]
+    fn drain_refill() {
+        let b: DrainableBintCell = DrainableBintCell::new_with_max_capacity(8, 4, 6);
+
+        assert_eq!(None, b.up_x(5));
+        assert_eq!(4, b.used());
+        assert_eq!(6, b.refill(usize::MAX));
+        assert_eq!(0, b.used());
+        assert_eq!(Some(2), b.down_x(2));
+        assert_eq!((4, 0), (b.remaining(), b.used()));
+
+        b.set_capacity(10);
+        assert_eq!((6, 6, 0), (b.remaining(), b.original_capacity(), b.used()));
+        assert_eq!(Some(0), b.down_x(2));
+        b.reset_capacity();
+        assert_eq!(6, b.remaining());
+    }
+
+    #[test]
+    fn until_visits_each_once() {
+        let b: Bint<u16> = Bint::new_with_value(1_000, 10);
+        let mut visited = Vec::new();
+
+        assert_eq!(
+            None,
+            b.down_until(|value| {
+                visited.push(value);
+                false
+            })
+        );
+        assert_eq!(1_000, visited.len());
+        assert_eq!(Some(&9), visited.first());
+        assert_eq!(Some(&10), visited.last());
+        assert_eq!(None, Bint::<u8>::new(0).up_until(|_| true));
+    }
+
+    #[test]
+    fn insert_remove_tracks_member() {
+        let members: Vec<u8> = (0..6).collect();
+
+        for value in 0..6 {
+            for position in 0..=6 {
+                let b: Bint = Bint::new_with_value(6, value);
+                let mut grown = members.clone();
+                grown.insert(usize::from(position), 99);
+                let inserted = b.insert_at(position).unwrap();
+                assert_eq!(value, grown[usize::from(inserted.value())]);
+
+                if position < 6 {
+                    let mut shrunk = members.clone();
+                    shrunk.remove(usize::from(position));
+                    let removed = b.remove_at(position).unwrap();
+                    let expected = if position == value {
+                        (value + 1) % 6
+                    } else {
+                        value
+                    };
+                    assert_eq!(expected, shrunk[usize::from(removed.value())]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn insert_remove_edges() {
+        assert_eq!(Ok(Bint::new(1)), Bint::<u8>::new(0).insert_at(0));
+        assert_eq!(Ok(Bint::new(0)), Bint::<u8>::new(1).remove_at(0));
+        assert_eq!(
+            Err(BintError::ValueOutOfRange {
+                value: 0,
+                boundary: 0
+            }),
+            Bint::<u8>::new(0).remove_at(0)
+        );
+        assert_eq!(Err(BintError::Overflow), Bint::<u8>::default().insert_at(0));
+    }
+
+    #[test]
+    fn distances_agree() {
+        let boundary: u8 = 7;
+
+        for (a, b) in (0..boundary).flat_map(|a| (0..boundary).map(move |b| (a, b))) {
+            let a: Bint = Bint::new_with_value(boundary, a);
+            let b = Bint::new_with_value(boundary, b);
+            let up = a.distance_up(b).unwrap();
+            let down = a.distance_down(b).unwrap();
+            let signed = a.signed_distance(b).unwrap();
+
+            assert_eq!(b, a.up_x(u64::from(up)));
+            assert_eq!(b, a.down_x(u64::from(down)));
+            assert_eq!(b, a.step(i64::try_from(signed).unwrap()));
+            assert!((-3..=3).contains(&signed));
+            assert_eq!(up.min(down), u8::try_from(signed.unsigned_abs()).unwrap());
+        }
+    }
+
+    #[test]
+    fn distances_wide() {
+        let a: Bint<u128> = Bint::new(u128::MAX);
+        let b = Bint::new_with_value(u128::MAX, u128::MAX / 2);
+
+        assert_eq!(Some(i128::MAX), a.signed_distance(b));
+        assert_eq!(Some(-i128::MAX), b.signed_distance(a));
+        assert_eq!(Some(0), Bint::<u8>::new(0).signed_distance(Bint::new(0)));
+    }
+
+    #[test]
+    fn signed_distance_bounds() {
+        for n in 1..=20_u8 {
+            let a: Bint = Bint::new(n);
+            let (low, high) = (-i128::from(n / 2), i128::from((n - 1) / 2));
+            let distances: Vec<i128> = a.lap().map(|b| a.signed_distance(b).unwrap()).collect();
+
+            assert_eq!(Some(&low), distances.iter().min());
+            assert_eq!(Some(&high), distances.iter().max());
+        }
+
+        let odd: Bint = Bint::new(7);
+        assert_eq!(Some(3), odd.signed_distance(Bint::new_with_value(7, 3)));
+        assert_eq!(Some(-3), odd.signed_distance(Bint::new_with_value(7, 4)));
+    }
 }
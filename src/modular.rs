@@ -0,0 +1,151 @@
+//! Modular arithmetic on widened values, shared by the bounded types.
+//!
+//! Every function expects a non-zero `boundary`.
+
+/// Moves `value` up `x` steps, wrapping at `boundary`.
+pub(crate) fn up(value: u128, x: u128, boundary: u128) -> u128 {
+    up_with_carry(value, x, boundary).0
+}
+
+/// Moves `value` up `x` steps, also returning how many times it wrapped to 0.
+pub(crate) fn up_with_carry(value: u128, x: u128, boundary: u128) -> (u128, u128) {
+    let value = value % boundary;
+    let laps = x / boundary;
+    let x = x % boundary;
+    let room = boundary - value;
+    if x >= room {
+        (x - room, laps + 1)
+    } else {
+        (value + x, laps)
+    }
+}
+
+/// Moves `value` down `x` steps, wrapping below 0 to `boundary - 1`.
+pub(crate) fn down(value: u128, x: u128, boundary: u128) -> u128 {
+    down_with_carry(value, x, boundary).0
+}
+
+/// Moves `value` down `x` steps, also returning how many times it wrapped below 0.
+pub(crate) fn down_with_carry(value: u128, x: u128, boundary: u128) -> (u128, u128) {
+    let value = value % boundary;
+    let laps = x / boundary;
+    let x = x % boundary;
+    if x > value {
+        (boundary - (x - value), laps + 1)
+    } else {
+        (value - x, laps)
+    }
+}
+
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Multiplies `a` and `b` modulo `boundary` without overflowing.
+pub(crate) fn mul(a: u128, b: u128, boundary: u128) -> u128 {
+    let mut a = a % boundary;
+    let mut b = b % boundary;
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = up(product, a, boundary);
+        }
+        a = up(a, a, boundary);
+        b >>= 1;
+    }
+    product
+}
+
+/// The multiplicative inverse of `a` modulo `boundary`. `a` and `boundary` must be coprime.
+pub(crate) fn inverse(a: u128, boundary: u128) -> u128 {
+    let (mut old_r, mut r) = (a % boundary, boundary);
+    let (mut old_s, mut s) = (1 % boundary, 0);
+    while r != 0 {
+        let q = old_r / r;
+        let next_r = old_r - q * r;
+        old_r = r;
+        r = next_r;
+        let next_s = down(old_s, mul(q, s, boundary), boundary);
+        old_s = s;
+        s = next_s;
+    }
+    old_s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn up_matches_stepping() {
+        for boundary in 1..12 {
+            for value in 0..boundary * 2 {
+                let mut stepped = value;
+                for x in 1..40 {
+                    stepped = (stepped + 1) % boundary;
+                    assert_eq!(stepped, up(value, x, boundary));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn down_matches_stepping() {
+        for boundary in 1..12 {
+            for value in 0..boundary {
+                let mut stepped = value;
+                for x in 1..40 {
+                    stepped = if stepped == 0 {
+                        boundary - 1
+                    } else {
+                        stepped - 1
+                    };
+                    assert_eq!(stepped, down(value, x, boundary));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn carries() {
+        assert_eq!((1, 0), up_with_carry(0, 1, 6));
+        assert_eq!((0, 1), up_with_carry(5, 1, 6));
+        assert_eq!((5, 2), up_with_carry(5, 12, 6));
+        assert_eq!((0, 7), up_with_carry(0, 7, 1));
+        assert_eq!((5, 1), down_with_carry(0, 1, 6));
+        assert_eq!((0, 0), down_with_carry(5, 5, 6));
+        assert_eq!((0, 2), down_with_carry(5, 17, 6));
+    }
+
+    #[test]
+    fn inverses() {
+        for boundary in 1..30 {
+            for a in 1..boundary {
+                if gcd(a, boundary) == 1 {
+                    assert_eq!(1 % boundary, mul(a, inverse(a, boundary), boundary));
+                }
+            }
+        }
+        let big = u128::MAX - 158; // the largest prime below 2^128
+        assert_eq!(1, mul(3, inverse(3, big), big));
+        assert_eq!(1, mul(big - 1, big - 1, big));
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!(0, up(u128::MAX - 1, 1, u128::MAX));
+        assert_eq!(u128::MAX - 2, up(u128::MAX - 1, u128::MAX - 1, u128::MAX));
+        assert_eq!(u128::MAX - 1, down(0, 1, u128::MAX));
+        assert_eq!(1, down(0, u128::MAX - 1, u128::MAX));
+        assert_eq!(
+            (u128::MAX - 1, 1),
+            up_with_carry(u128::MAX - 1, u128::MAX, u128::MAX)
+        );
+        assert_eq!((0, u128::MAX), down_with_carry(0, u128::MAX, 1));
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt;
+
+use crate::{radix, Bint, BintError, Unsigned};
+
+/// Odometer: A chain of [`Bint`] digits, each with its own boundary, where a digit wrapping
+/// to 0 carries into the next one.
+///
+/// The first digit is the least significant one, so a clock is made from seconds, minutes
+/// and hours, in that order.
+///
+/// Usage:
+///
+/// ```
+/// use bint::Odometer;
+///
+/// let mut clock: Odometer = Odometer::new(&[60, 60, 24]).unwrap();
+///
+/// clock.up_x(3_599);
+/// assert_eq!(vec![59, 59, 0], clock.values());
+///
+/// clock.up();
+/// assert_eq!(vec![0, 0, 1], clock.values());
+/// assert_eq!("1:0:0", clock.to_string());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Odometer<T: Unsigned = u8> {
+    digits: Vec<Bint<T>>,
+}
+
+impl<T: Unsigned> Odometer<T> {
+    /// Creates an odometer reading 0 on every digit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if any of the boundaries is 0.
+    ///
+    /// ```
+    /// use bint::{BintError, Odometer};
+    ///
+    /// let odometer: Odometer = Odometer::new(&[6, 10]).unwrap();
+    /// assert_eq!(vec![0, 0], odometer.values());
+    ///
+    /// assert_eq!(Err(BintError::ZeroBoundary), Odometer::<u8>::new(&[6, 0]));
+    /// ```
+    pub fn new(boundaries: &[T]) -> Result<Odometer<T>, BintError> {
+        Odometer::from_digits(
+            boundaries
+                .iter()
+                .map(|&boundary| Bint::new(boundary))
+                .collect(),
+        )
+    }
+
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if any of the digits has a zero boundary.
+    ///
+    /// ```
+    /// use bint::{Bint, Odometer};
+    ///
+    /// let odometer: Odometer = Odometer::from_digits(vec![
+    ///     Bint::new_with_value(6, 5),
+    ///     Bint::new_with_value(10, 3),
+    /// ])
+    /// .unwrap();
+    ///
+    /// assert_eq!(vec![5, 3], odometer.values());
+    /// ```
+    pub fn from_digits(digits: Vec<Bint<T>>) -> Result<Odometer<T>, BintError> {
+        if digits.iter().any(|digit| digit.boundary() == T::ZERO) {
+            return Err(BintError::ZeroBoundary);
+        }
+        Ok(Odometer { digits })
+    }
+
+    /// Creates the odometer reading that is `index` steps up from 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if any of the boundaries is 0, and
+    /// `BintError::ValueOutOfRange` if the index isn't below the odometer's span.
+    ///
+    /// ```
+    /// use bint::{BintError, Odometer};
+    ///
+    /// let clock: Odometer = Odometer::from_index(&[60, 60, 24], 3_723).unwrap();
+    /// assert_eq!(vec![3, 2, 1], clock.values());
+    ///
+    /// assert_eq!(
+    ///     Err(BintError::ValueOutOfRange { value: 60, boundary: 60 }),
+    ///     Odometer::<u8>::from_index(&[6, 10], 60)
+    /// );
+    /// ```
+    pub fn from_index(boundaries: &[T], index: u128) -> Result<Odometer<T>, BintError> {
+        Ok(Odometer {
+            digits: radix::decode(index, boundaries)?,
+        })
+    }
+
+    #[must_use]
+    pub fn digits(&self) -> &[Bint<T>] {
+        &self.digits
+    }
+
+    #[must_use]
+    pub fn boundaries(&self) -> Vec<T> {
+        self.digits.iter().map(Bint::boundary).collect()
+    }
+
+    #[must_use]
+    pub fn values(&self) -> Vec<T> {
+        self.digits.iter().map(Bint::value).collect()
+    }
+
+    /// The number of steps up from 0 to the current reading, or `None` if it doesn't fit in a
+    /// `u128`.
+    ///
+    /// ```
+    /// use bint::Odometer;
+    ///
+    /// let mut clock: Odometer = Odometer::new(&[60, 60, 24]).unwrap();
+    /// clock.up_x(3_723);
+    ///
+    /// assert_eq!(Some(3_723), clock.index());
+    /// ```
+    #[must_use]
+    pub fn index(&self) -> Option<u128> {
+        radix::encode(&self.digits).ok()
+    }
+
+    /// The number of distinct readings, which is the product of the boundaries, or `None` if
+    /// it doesn't fit in a `u128`.
+    ///
+    /// ```
+    /// use bint::Odometer;
+    ///
+    /// let clock: Odometer = Odometer::new(&[60, 60, 24]).unwrap();
+    /// assert_eq!(Some(86_400), clock.span());
+    ///
+    /// let huge: Odometer<u64> = Odometer::new(&[u64::MAX, u64::MAX, 2]).unwrap();
+    /// assert_eq!(None, huge.span());
+    /// ```
+    #[must_use]
+    pub fn span(&self) -> Option<u128> {
+        radix::span(&self.boundaries())
+    }
+
+    /// Moves up one step, returning true if the most significant digit overflowed.
+    ///
+    /// ```
+    /// use bint::Odometer;
+    ///
+    /// let mut odometer: Odometer = Odometer::new(&[2, 2]).unwrap();
+    ///
+    /// assert!(!odometer.up());
+    /// assert!(!odometer.up());
+    /// assert!(!odometer.up());
+    /// assert!(odometer.up());
+    /// assert_eq!(vec![0, 0], odometer.values());
+    /// ```
+    pub fn up(&mut self) -> bool {
+        self.up_x(1) > 0
+    }
+
+    /// Moves down one step, returning true if the most significant digit wrapped below 0.
+    ///
+    /// ```
+    /// use bint::Odometer;
+    ///
+    /// let mut odometer: Odometer = Odometer::new(&[2, 2]).unwrap();
+    ///
+    /// assert!(odometer.down());
+    /// assert_eq!(vec![1, 1], odometer.values());
+    /// ```
+    pub fn down(&mut self) -> bool {
+        self.down_x(1) > 0
+    }
+
+    /// Moves up x steps, returning how many times the most significant digit overflowed.
+    ///
+    /// ```
+    /// use bint::Odometer;
+    ///
+    /// let mut clock: Odometer = Odometer::new(&[60, 60, 24]).unwrap();
+    ///
+    /// assert_eq!(2, clock.up_x(2 * 86_400 + 61));
+    /// assert_eq!(vec![1, 1, 0], clock.values());
+    /// ```
+    pub fn up_x(&mut self, x: u64) -> u64 {
+        let mut carry = x;
+        for digit in &mut self.digits {
+            if carry == 0 {
+                break;
+            }
+            let (bint, laps) = digit.up_with_carry(carry);
+            *digit = bint;
+            carry = laps;
+        }
+        carry
+    }
+
+    /// Moves down x steps, returning how many times the most significant digit wrapped
+    /// below 0.
+    ///
+    /// ```
+    /// use bint::Odometer;
+    ///
+    /// let mut clock: Odometer = Odometer::new(&[60, 60, 24]).unwrap();
+    ///
+    /// assert_eq!(1, clock.down_x(61));
+    /// assert_eq!(vec![59, 58, 23], clock.values());
+    /// ```
+    pub fn down_x(&mut self, x: u64) -> u64 {
+        let mut borrow = x;
+        for digit in &mut self.digits {
+            if borrow == 0 {
+                break;
+            }
+            let (bint, laps) = digit.down_with_carry(borrow);
+            *digit = bint;
+            borrow = laps;
+        }
+        borrow
+    }
+
+    /// Sets every digit back to 0.
+    pub fn reset(&mut self) {
+        for digit in &mut self.digits {
+            *digit = Bint::new(digit.boundary());
+        }
+    }
+}
+
+impl<T: Unsigned> fmt::Display for Odometer<T> {
+    /// Writes the digits most significant first, separated by colons.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, digit) in self.digits.iter().rev().enumerate() {
+            if i > 0 {
+                write!(f, ":")?;
+            }
+            write!(f, "{}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn up_matches_index() {
+        let mut odometer: Odometer = Odometer::new(&[3, 1, 4, 5]).unwrap();
+
+        for index in 0..60 {
+            assert_eq!(Some(index), odometer.index());
+            assert_eq!(
+                odometer,
+                Odometer::from_index(&[3, 1, 4, 5], index).unwrap()
+            );
+            assert_eq!(index == 59, odometer.up());
+        }
+        assert_eq!(Some(0), odometer.index());
+    }
+
+    #[test]
+    fn down_matches_index() {
+        let mut odometer: Odometer = Odometer::new(&[3, 1, 4, 5]).unwrap();
+
+        assert!(odometer.down());
+        for index in (0..60).rev() {
+            assert_eq!(Some(index), odometer.index());
+            odometer.down();
+        }
+    }
+
+    #[test]
+    fn wide() {
+        let mut odometer: Odometer<u128> = Odometer::new(&[u128::MAX, 2]).unwrap();
+
+        assert_eq!(1, odometer.down_x(1));
+        assert_eq!(None, odometer.span());
+        assert_eq!(None, odometer.index());
+        assert_eq!(vec![u128::MAX - 1, 1], odometer.values());
+        assert_eq!(1, odometer.up_x(1));
+        assert_eq!(Some(0), odometer.index());
+    }
+
+    #[test]
+    fn empty() {
+        let mut odometer: Odometer = Odometer::new(&[]).unwrap();
+
+        assert_eq!(Some(1), odometer.span());
+        assert_eq!(Some(0), odometer.index());
+        assert_eq!(5, odometer.up_x(5));
+        assert_eq!("", odometer.to_string());
+    }
+
+    #[test]
+    fn reset() {
+        let mut odometer: Odometer<u16> = Odometer::from_index(&[1_000, 1_000], 123_456).unwrap();
+
+        odometer.reset();
+
+        assert_eq!(Odometer::new(&[1_000, 1_000]).unwrap(), odometer);
+    }
+}
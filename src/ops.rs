@@ -0,0 +1,244 @@
+//! Modular arithmetic operators for [`Bint`].
+//!
+//! Integers on the right hand side move the value up or down. `Bint`s on the right hand side
+//! must share the boundary of the left hand side, otherwise the operator panics. Use
+//! [`Bint::checked_add`] and [`Bint::checked_sub`] to get `None` instead.
+
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::{Bint, Unsigned};
+
+impl<T: Unsigned> Bint<T> {
+    /// Adds the values of two `Bint`s, returning `None` if their boundaries differ.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!(Some(Bint::new_with_value(6, 1)), a.checked_add(Bint::new_with_value(6, 3)));
+    /// assert_eq!(None, a.checked_add(Bint::new_with_value(7, 3)));
+    /// ```
+    #[must_use]
+    pub fn checked_add(self, rhs: Bint<T>) -> Option<Bint<T>> {
+        if self.boundary == rhs.boundary {
+            Some(self + rhs.value)
+        } else {
+            None
+        }
+    }
+
+    /// Subtracts the value of one `Bint` from another, returning `None` if their boundaries
+    /// differ.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 1);
+    ///
+    /// assert_eq!(Some(Bint::new_with_value(6, 4)), a.checked_sub(Bint::new_with_value(6, 3)));
+    /// assert_eq!(None, a.checked_sub(Bint::new_with_value(7, 3)));
+    /// ```
+    #[must_use]
+    pub fn checked_sub(self, rhs: Bint<T>) -> Option<Bint<T>> {
+        if self.boundary == rhs.boundary {
+            Some(self - rhs.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Unsigned> Add<T> for Bint<T> {
+    type Output = Bint<T>;
+
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(6, 4);
+    ///
+    /// assert_eq!(1, (seat + 3).value());
+    /// ```
+    fn add(self, rhs: T) -> Bint<T> {
+        self.up_wide(rhs.to_u128())
+    }
+}
+
+impl<T: Unsigned> Add for Bint<T> {
+    type Output = Bint<T>;
+
+    /// # Panics
+    ///
+    /// Panics if the boundaries differ.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 4);
+    /// let b: Bint = Bint::new_with_value(6, 5);
+    ///
+    /// assert_eq!(3, (a + b).value());
+    /// ```
+    fn add(self, rhs: Bint<T>) -> Bint<T> {
+        assert_eq!(
+            self.boundary, rhs.boundary,
+            "cannot add Bints with different boundaries"
+        );
+        self + rhs.value
+    }
+}
+
+impl<T: Unsigned> AddAssign<T> for Bint<T> {
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let mut seat: Bint = Bint::new_with_value(6, 4);
+    /// seat += 3;
+    ///
+    /// assert_eq!(1, seat.value());
+    /// ```
+    fn add_assign(&mut self, rhs: T) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Unsigned> AddAssign for Bint<T> {
+    /// # Panics
+    ///
+    /// Panics if the boundaries differ.
+    fn add_assign(&mut self, rhs: Bint<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Unsigned> Sub<T> for Bint<T> {
+    type Output = Bint<T>;
+
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let seat: Bint = Bint::new_with_value(6, 1);
+    ///
+    /// assert_eq!(4, (seat - 3).value());
+    /// ```
+    fn sub(self, rhs: T) -> Bint<T> {
+        self.down_wide(rhs.to_u128())
+    }
+}
+
+impl<T: Unsigned> Sub for Bint<T> {
+    type Output = Bint<T>;
+
+    /// # Panics
+    ///
+    /// Panics if the boundaries differ.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let a: Bint = Bint::new_with_value(6, 1);
+    /// let b: Bint = Bint::new_with_value(6, 5);
+    ///
+    /// assert_eq!(2, (a - b).value());
+    /// ```
+    fn sub(self, rhs: Bint<T>) -> Bint<T> {
+        assert_eq!(
+            self.boundary, rhs.boundary,
+            "cannot subtract Bints with different boundaries"
+        );
+        self - rhs.value
+    }
+}
+
+impl<T: Unsigned> SubAssign<T> for Bint<T> {
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let mut seat: Bint = Bint::new_with_value(6, 1);
+    /// seat -= 3;
+    ///
+    /// assert_eq!(4, seat.value());
+    /// ```
+    fn sub_assign(&mut self, rhs: T) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Unsigned> SubAssign for Bint<T> {
+    /// # Panics
+    ///
+    /// Panics if the boundaries differ.
+    fn sub_assign(&mut self, rhs: Bint<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Unsigned> Neg for Bint<T> {
+    type Output = Bint<T>;
+
+    /// The additive inverse, so that `b + -b` is always 0.
+    ///
+    /// ```
+    /// use bint::Bint;
+    ///
+    /// let b: Bint = Bint::new_with_value(6, 2);
+    ///
+    /// assert_eq!(4, (-b).value());
+    /// assert_eq!(0, (b + -b).value());
+    /// ```
+    fn neg(self) -> Bint<T> {
+        Bint::new(self.boundary) - self.value
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_matches_up_x() {
+        let b: Bint<u16> = Bint::new_with_value(1_000, 999);
+
+        assert_eq!(b.up_x(65_535), b + u16::MAX);
+        assert_eq!(b.down_x(65_535), b - u16::MAX);
+    }
+
+    #[test]
+    fn add_wide() {
+        let b: Bint<u128> = Bint::new_with_value(u128::MAX, 7);
+
+        assert_eq!(6, (b + (u128::MAX - 1)).value);
+        assert_eq!(8, (b - (u128::MAX - 1)).value);
+    }
+
+    #[test]
+    fn assign() {
+        let mut a: Bint = Bint::new_with_value(10, 3);
+        a += Bint::new_with_value(10, 9);
+        assert_eq!(2, a.value);
+
+        a -= Bint::new_with_value(10, 9);
+        assert_eq!(3, a.value);
+    }
+
+    #[test]
+    fn neg_zero() {
+        let b: Bint = Bint::new(6);
+
+        assert_eq!(b, -b);
+        assert_eq!(Bint::new(0), -Bint::<u8>::new(0));
+    }
+
+    #[test]
+    #[should_panic(expected = "different boundaries")]
+    fn add_different_boundaries() {
+        let _ = Bint::<u8>::new(6) + Bint::new(7);
+    }
+
+    #[test]
+    #[should_panic(expected = "different boundaries")]
+    fn sub_different_boundaries() {
+        let _ = Bint::<u8>::new(6) - Bint::new(7);
+    }
+}
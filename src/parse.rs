@@ -0,0 +1,179 @@
+//! Parsing the `value/boundary` text format written by the alternate form of `Display`.
+
+use std::str::FromStr;
+
+use crate::{Bint, BintCell, DrainableBintCell, ParseBintError, Unsigned};
+
+impl<T: Unsigned> FromStr for Bint<T> {
+    type Err = ParseBintError;
+
+    /// Parses `value/boundary`, rejecting a value that's out of range. A zero boundary is
+    /// rejected too, apart from `0/0`, which is what the alternate form of `Display` writes
+    /// for `Bint::new(0)`, so that everything written parses back.
+    ///
+    /// ```
+    /// use bint::{Bint, BintError, ParseBintError};
+    ///
+    /// let b: Bint<u16> = "999/1000".parse().unwrap();
+    ///
+    /// assert_eq!(Bint::new_with_value(1_000, 999), b);
+    /// assert_eq!(Ok(Bint::new(0)), "0/0".parse::<Bint>());
+    /// assert_eq!(
+    ///     Err(ParseBintError::Invalid(BintError::ZeroBoundary)),
+    ///     "1/0".parse::<Bint>()
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<Bint<T>, ParseBintError> {
+        let (value, boundary) = s.split_once('/').ok_or(ParseBintError::Format)?;
+        let value = value.parse().map_err(ParseBintError::Value)?;
+        let boundary = boundary.parse().map_err(ParseBintError::Boundary)?;
+        if (value, boundary) == (T::ZERO, T::ZERO) {
+            return Ok(Bint::new(T::ZERO));
+        }
+        Ok(Bint::try_new(boundary, value)?)
+    }
+}
+
+impl<T: Unsigned> FromStr for BintCell<T> {
+    type Err = ParseBintError;
+
+    /// Parses `value/boundary`, like [`Bint`].
+    fn from_str(s: &str) -> Result<BintCell<T>, ParseBintError> {
+        s.parse::<Bint<T>>().map(BintCell::from)
+    }
+}
+
+impl<T: Unsigned> FromStr for DrainableBintCell<T> {
+    type Err = ParseBintError;
+
+    /// Parses `value/boundary [cap capacity]`. The capacity becomes both the remaining and the
+    /// original capacity, unless it's written as `remaining/original`, and a maximum capacity
+    /// can follow it as in `[cap 2/4 max 8]`.
+    ///
+    /// ```
+    /// use bint::{BintError, DrainableBintCell, ParseBintError};
+    ///
+    /// let b: DrainableBintCell = "3/6 [cap 4]".parse().unwrap();
+    ///
+    /// assert_eq!(DrainableBintCell::new_with_value(6, 4, 3), b);
+    /// assert!("3/6".parse::<DrainableBintCell>().is_err());
+    ///
+    /// let capped: DrainableBintCell = "2/6 [cap 2/4 max 8]".parse().unwrap();
+    ///
+    /// assert_eq!(2, capped.remaining());
+    /// assert_eq!(4, capped.original_capacity());
+    /// assert_eq!(Some(8), capped.max_capacity());
+    /// assert_eq!(
+    ///     Err(ParseBintError::Invalid(BintError::CapacityAboveMax { capacity: 9, max: 8 })),
+    ///     "2/6 [cap 9 max 8]".parse::<DrainableBintCell>()
+    /// );
+    /// ```
+    fn from_str(s: &str) -> Result<DrainableBintCell<T>, ParseBintError> {
+        let (bint, capacity) = s
+            .strip_suffix(']')
+            .and_then(|s| s.split_once(" [cap "))
+            .ok_or(ParseBintError::Format)?;
+        let bint = bint.parse::<Bint<T>>()?;
+        let (capacity, max_capacity) = match capacity.split_once(" max ") {
+            Some((capacity, max)) => (capacity, Some(parse_capacity(max)?)),
+            None => (capacity, None),
+        };
+        let (remaining, original) = capacity.split_once('/').unwrap_or((capacity, capacity));
+        Ok(DrainableBintCell::try_from_parts(
+            BintCell::from(bint),
+            parse_capacity(remaining)?,
+            parse_capacity(original)?,
+            max_capacity,
+        )?)
+    }
+}
+
+fn parse_capacity(s: &str) -> Result<usize, ParseBintError> {
+    s.parse().map_err(ParseBintError::Capacity)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let b: Bint<u128> = Bint::new_with_value(u128::MAX, u128::MAX - 1);
+        assert_eq!(Ok(b), format!("{:#}", b).parse());
+
+        let cell: BintCell<u16> = BintCell::new_with_value(1_000, 999);
+        assert_eq!(Ok(cell.clone()), format!("{:#}", cell).parse());
+
+        let drainable: DrainableBintCell<u64> = DrainableBintCell::new_with_value(10, 7, 9);
+        assert_eq!(Ok(drainable.clone()), format!("{:#}", drainable).parse());
+    }
+
+    #[test]
+    fn round_trip_drained_and_capped() {
+        let capped: DrainableBintCell = DrainableBintCell::new_with_max_capacity(6, 4, 8);
+        capped.up().unwrap();
+        assert_eq!(Ok(capped.clone()), format!("{:#}", capped).parse());
+
+        capped.refill(6);
+        assert_eq!("1/6 [cap 8/4 max 8]", format!("{:#}", capped));
+        assert_eq!(Ok(capped.clone()), format!("{:#}", capped).parse());
+
+        let drained: DrainableBintCell<u32> = DrainableBintCell::new(100, 3);
+        assert_eq!(None, drained.down_x(5));
+        assert_eq!("97/100 [cap 0/3]", format!("{:#}", drained));
+        assert_eq!(Ok(drained.clone()), format!("{:#}", drained).parse());
+    }
+
+    #[test]
+    fn round_trip_zero_boundary() {
+        let b: Bint<u64> = Bint::new(0);
+        assert_eq!("0/0", format!("{:#}", b));
+        assert_eq!(Ok(b), format!("{:#}", b).parse());
+
+        let cell: BintCell = BintCell::new(0);
+        assert_eq!(Ok(cell.clone()), format!("{:#}", cell).parse());
+
+        let drainable: DrainableBintCell = DrainableBintCell::new(0, 2);
+        assert_eq!("0/0 [cap 2]", format!("{:#}", drainable));
+        assert_eq!(Ok(drainable.clone()), format!("{:#}", drainable).parse());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(ParseBintError::Format), "".parse::<Bint>());
+        assert!(matches!(
+            "3/256".parse::<Bint>(),
+            Err(ParseBintError::Boundary(_))
+        ));
+        assert!(matches!(
+            " 3/6".parse::<Bint>(),
+            Err(ParseBintError::Value(_))
+        ));
+        assert!(matches!(
+            "3/6 [cap -1]".parse::<DrainableBintCell>(),
+            Err(ParseBintError::Capacity(_))
+        ));
+        assert_eq!(
+            Err(ParseBintError::Format),
+            "3/6 [cap 4".parse::<DrainableBintCell>()
+        );
+        assert_eq!(
+            Err(ParseBintError::Invalid(crate::BintError::ZeroBoundary)),
+            "1/0 [cap 4]".parse::<DrainableBintCell>()
+        );
+        assert!(matches!(
+            "3/6 [cap 2/x]".parse::<DrainableBintCell>(),
+            Err(ParseBintError::Capacity(_))
+        ));
+        assert_eq!(
+            Err(ParseBintError::Invalid(
+                crate::BintError::CapacityAboveMax {
+                    capacity: 5,
+                    max: 4
+                }
+            )),
+            "3/6 [cap 2/5 max 4]".parse::<DrainableBintCell>()
+        );
+    }
+}
@@ -0,0 +1,563 @@
+//! Overflow policies: what happens when a step goes past the top of the range or below 0.
+//!
+//! The plain `up` and `down` methods always wrap. The `*_with` methods on [`Bint`],
+//! [`BintCell`] and [`DrainableBintCell`] take an [`OverflowPolicy`] instead:
+//!
+//! * [`Wrapping`] wraps around, like the plain methods.
+//! * [`Saturating`] stops at 0 and at the top of the range.
+//! * [`Reflecting`] bounces off either end and remembers which way it's heading.
+//! * [`Erroring`] refuses any step that would wrap.
+//!
+//! ```
+//! use bint::policy::{Direction, Reflecting, Saturating};
+//! use bint::{Bint, BintCell};
+//!
+//! let volume: Bint = Bint::new_with_value(11, 9);
+//! assert_eq!(Ok(Bint::new_with_value(11, 10)), volume.up_x_with(5, &mut Saturating));
+//!
+//! let frame: BintCell = BintCell::new(4);
+//! let mut reflecting = Reflecting::new();
+//! let frames: Vec<u8> = (0..8).map(|_| frame.up_with(&mut reflecting).unwrap()).collect();
+//!
+//! assert_eq!(vec![1, 2, 3, 2, 1, 0, 1, 2], frames);
+//! assert_eq!(Direction::Up, reflecting.direction());
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::{Bint, BintCell, BintError, DrainableBintCell, Unsigned};
+
+/// Moves a [`Bint`] x spots up or down, deciding what happens at the ends of its range.
+///
+/// The methods take `&mut self` so that a policy can keep state between steps, like the
+/// direction of [`Reflecting`]. The trait is generic over the integer type rather than its
+/// methods, so a policy can be picked at runtime as a `Box<dyn OverflowPolicy<T>>`.
+///
+/// ```
+/// use bint::policy::{Erroring, OverflowPolicy, Saturating, Wrapping};
+/// use bint::Bint;
+///
+/// fn policy(name: &str) -> Box<dyn OverflowPolicy<u8>> {
+///     match name {
+///         "saturate" => Box::new(Saturating),
+///         "error" => Box::new(Erroring),
+///         _ => Box::new(Wrapping),
+///     }
+/// }
+///
+/// let b: Bint = Bint::new_with_value(6, 4);
+///
+/// assert_eq!(Ok(Bint::new_with_value(6, 5)), b.up_x_with(3, &mut policy("saturate")));
+/// assert!(b.up_x_with(3, &mut policy("error")).is_err());
+/// assert_eq!(Ok(Bint::new_with_value(6, 1)), b.up_x_with(3, &mut policy("wrap")));
+/// ```
+pub trait OverflowPolicy<T: Unsigned> {
+    /// # Errors
+    ///
+    /// Returns an error if the policy doesn't allow the step.
+    fn up_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError>;
+
+    /// # Errors
+    ///
+    /// Returns an error if the policy doesn't allow the step.
+    fn down_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError>;
+}
+
+impl<T: Unsigned, P: OverflowPolicy<T> + ?Sized> OverflowPolicy<T> for Box<P> {
+    fn up_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        (**self).up_x(bint, x)
+    }
+
+    fn down_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        (**self).down_x(bint, x)
+    }
+}
+
+/// Wraps around past either end, like [`Bint::up`] and [`Bint::down`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Wrapping;
+
+impl<T: Unsigned> OverflowPolicy<T> for Wrapping {
+    fn up_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        Ok(bint.up_x(x))
+    }
+
+    fn down_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        Ok(bint.down_x(x))
+    }
+}
+
+/// Stops at 0 and at one below the boundary.
+///
+/// ```
+/// use bint::policy::Saturating;
+/// use bint::Bint;
+///
+/// let b: Bint = Bint::new_with_value(6, 2);
+///
+/// assert_eq!(Ok(Bint::new_with_value(6, 5)), b.up_x_with(10, &mut Saturating));
+/// assert_eq!(Ok(Bint::new(6)), b.down_x_with(10, &mut Saturating));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Saturating;
+
+impl<T: Unsigned> OverflowPolicy<T> for Saturating {
+    fn up_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        let value = bint.value.to_u128().saturating_add(u128::from(x));
+        Ok(with_value(bint, value.min(top(bint))))
+    }
+
+    fn down_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        let value = bint.value.to_u128().saturating_sub(u128::from(x));
+        Ok(with_value(bint, value))
+    }
+}
+
+/// Refuses a step that would wrap, returning `BintError::WouldWrap` and leaving the value
+/// where it was.
+///
+/// ```
+/// use bint::policy::Erroring;
+/// use bint::{Bint, BintError};
+///
+/// let b: Bint = Bint::new_with_value(6, 2);
+///
+/// assert_eq!(Ok(Bint::new_with_value(6, 5)), b.up_x_with(3, &mut Erroring));
+/// assert_eq!(Err(BintError::WouldWrap), b.up_x_with(4, &mut Erroring));
+/// assert_eq!(Err(BintError::WouldWrap), b.down_x_with(3, &mut Erroring));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Erroring;
+
+impl<T: Unsigned> OverflowPolicy<T> for Erroring {
+    fn up_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        let value = bint
+            .value
+            .to_u128()
+            .checked_add(u128::from(x))
+            .filter(|value| *value <= top(bint))
+            .ok_or(BintError::WouldWrap)?;
+        Ok(with_value(bint, value))
+    }
+
+    fn down_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        let value = bint
+            .value
+            .to_u128()
+            .checked_sub(u128::from(x))
+            .ok_or(BintError::WouldWrap)?;
+        Ok(with_value(bint, value))
+    }
+}
+
+/// Which way a [`Reflecting`] policy is heading.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+}
+
+impl Direction {
+    #[must_use]
+    pub fn reverse(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+        }
+    }
+}
+
+impl Default for Direction {
+    fn default() -> Direction {
+        Direction::Up
+    }
+}
+
+/// Bounces off 0 and the top of the range, ping-ponging instead of wrapping.
+///
+/// Moving up goes in the current direction, and moving down goes against it. The direction
+/// flips on reaching either end, so moving up from the top heads back down. Moving down
+/// undoes moving up, direction included.
+///
+/// ```
+/// use bint::policy::{Direction, Reflecting};
+/// use bint::Bint;
+///
+/// let mut reflecting = Reflecting::new();
+/// let b: Bint = Bint::new_with_value(4, 1);
+///
+/// let c = b.up_x_with(4, &mut reflecting).unwrap();
+/// assert_eq!(1, c.value());
+/// assert_eq!(Direction::Down, reflecting.direction());
+///
+/// assert_eq!(Ok(b), c.down_x_with(4, &mut reflecting));
+/// assert_eq!(Direction::Up, reflecting.direction());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Reflecting {
+    direction: Direction,
+}
+
+impl Reflecting {
+    /// Creates a `Reflecting` policy heading up.
+    #[must_use]
+    pub fn new() -> Reflecting {
+        Reflecting::default()
+    }
+
+    #[must_use]
+    pub fn with_direction(direction: Direction) -> Reflecting {
+        Reflecting { direction }
+    }
+
+    #[must_use]
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn set_direction(&mut self, direction: Direction) {
+        self.direction = direction;
+    }
+}
+
+impl<T: Unsigned> OverflowPolicy<T> for Reflecting {
+    fn up_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        let (value, direction) = bounce(bint, self.direction, x);
+        self.direction = direction;
+        Ok(with_value(bint, value))
+    }
+
+    fn down_x(&mut self, bint: Bint<T>, x: u64) -> Result<Bint<T>, BintError> {
+        let (value, direction) = bounce(bint, mirror(bint, self.direction), x);
+        let bint = with_value(bint, value);
+        self.direction = mirror(bint, direction);
+        Ok(bint)
+    }
+}
+
+/// Moving down retraces moving up, so it's moving up with the direction reversed, except at
+/// the ends, where the direction is already the one leaving them.
+fn mirror<T: Unsigned>(bint: Bint<T>, direction: Direction) -> Direction {
+    if bint.value == T::ZERO {
+        Direction::Up
+    } else if bint.value.to_u128() == top(bint) {
+        Direction::Down
+    } else {
+        direction.reverse()
+    }
+}
+
+/// Moves x spots in the direction, turning around at either end. Returns the new value and
+/// the direction it's heading in.
+fn bounce<T: Unsigned>(bint: Bint<T>, direction: Direction, x: u64) -> (u128, Direction) {
+    let (value, top, x) = (bint.value.to_u128(), top(bint), u128::from(x));
+    if top == 0 || x == 0 {
+        return (value, direction);
+    }
+    let room = match direction {
+        Direction::Up => top - value,
+        Direction::Down => value,
+    };
+    if x < room {
+        return match direction {
+            Direction::Up => (value + x, direction),
+            Direction::Down => (value - x, direction),
+        };
+    }
+    // Having reached the end it was heading for, it turns around and each further full
+    // traversal turns it around again.
+    let rest = x - room;
+    let direction = if (rest / top) % 2 == 0 {
+        direction.reverse()
+    } else {
+        direction
+    };
+    match direction {
+        Direction::Up => (rest % top, direction),
+        Direction::Down => (top - rest % top, direction),
+    }
+}
+
+/// The highest value, widened. The degenerate zero boundary is treated like a boundary of 1.
+fn top<T: Unsigned>(bint: Bint<T>) -> u128 {
+    bint.boundary.to_u128().saturating_sub(1)
+}
+
+fn with_value<T: Unsigned>(bint: Bint<T>, value: u128) -> Bint<T> {
+    Bint::new_unchecked(bint.boundary, T::from_u128(value))
+}
+
+impl<T: Unsigned> Bint<T> {
+    /// Moves up one spot, letting the policy decide what happens past the top.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error if it doesn't allow the step.
+    pub fn up_with<P: OverflowPolicy<T> + ?Sized>(
+        self,
+        policy: &mut P,
+    ) -> Result<Bint<T>, BintError> {
+        policy.up_x(self, 1)
+    }
+
+    /// Moves down one spot, letting the policy decide what happens below 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error if it doesn't allow the step.
+    pub fn down_with<P: OverflowPolicy<T> + ?Sized>(
+        self,
+        policy: &mut P,
+    ) -> Result<Bint<T>, BintError> {
+        policy.down_x(self, 1)
+    }
+
+    /// # Errors
+    ///
+    /// Returns the policy's error if it doesn't allow the step.
+    pub fn up_x_with<P: OverflowPolicy<T> + ?Sized>(
+        self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<Bint<T>, BintError> {
+        policy.up_x(self, x)
+    }
+
+    /// # Errors
+    ///
+    /// Returns the policy's error if it doesn't allow the step.
+    pub fn down_x_with<P: OverflowPolicy<T> + ?Sized>(
+        self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<Bint<T>, BintError> {
+        policy.down_x(self, x)
+    }
+}
+
+impl<T: Unsigned> BintCell<T> {
+    /// Moves up one spot under the policy. See [`Bint::up_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    ///
+    /// ```
+    /// use bint::policy::Saturating;
+    /// use bint::BintCell;
+    ///
+    /// let volume: BintCell = BintCell::new_with_value(11, 9);
+    ///
+    /// assert_eq!(Ok(10), volume.up_with(&mut Saturating));
+    /// assert_eq!(Ok(10), volume.up_with(&mut Saturating));
+    /// ```
+    pub fn up_with<P: OverflowPolicy<T> + ?Sized>(&self, policy: &mut P) -> Result<T, BintError> {
+        self.up_x_with(1, policy)
+    }
+
+    /// Moves down one spot under the policy. See [`Bint::down_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    pub fn down_with<P: OverflowPolicy<T> + ?Sized>(&self, policy: &mut P) -> Result<T, BintError> {
+        self.down_x_with(1, policy)
+    }
+
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    pub fn up_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<T, BintError> {
+        let bint = policy.up_x(Bint::from(self), x)?;
+        self.set_unchecked(bint.value);
+        Ok(bint.value)
+    }
+
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value unchanged, if it doesn't allow the step.
+    ///
+    /// ```
+    /// use bint::policy::Erroring;
+    /// use bint::BintCell;
+    ///
+    /// let b: BintCell = BintCell::new_with_value(6, 2);
+    ///
+    /// assert!(b.down_x_with(3, &mut Erroring).is_err());
+    /// assert_eq!(Ok(0), b.down_x_with(2, &mut Erroring));
+    /// ```
+    pub fn down_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<T, BintError> {
+        let bint = policy.down_x(Bint::from(self), x)?;
+        self.set_unchecked(bint.value);
+        Ok(bint.value)
+    }
+}
+
+impl<T: Unsigned> DrainableBintCell<T> {
+    /// Moves up one spot under the policy, draining the capacity like `up`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    pub fn up_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.up_x_with(1, policy)
+    }
+
+    /// Moves down one spot under the policy, draining the capacity like `down`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    pub fn down_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.down_x_with(1, policy)
+    }
+
+    /// Moves up x spots under the policy. Like `up_x`, when there isn't enough capacity left
+    /// it moves as far as the capacity allows and returns `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    ///
+    /// ```
+    /// use bint::policy::Saturating;
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new(6, 8);
+    ///
+    /// assert_eq!(Ok(Some(5)), b.up_x_with(7, &mut Saturating));
+    /// assert_eq!(Ok(None), b.up_x_with(2, &mut Saturating));
+    /// assert!(!b.has_capacity());
+    /// ```
+    pub fn up_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.drain_with(x, |bint, drained| policy.up_x(bint, drained))
+    }
+
+    /// Moves down x spots under the policy. See [`DrainableBintCell::up_x_with`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the policy's error, leaving the value and the capacity unchanged, if it doesn't
+    /// allow the step.
+    ///
+    /// ```
+    /// use bint::policy::Erroring;
+    /// use bint::DrainableBintCell;
+    ///
+    /// let b: DrainableBintCell = DrainableBintCell::new_with_value(6, 4, 3);
+    ///
+    /// assert!(b.down_x_with(4, &mut Erroring).is_err());
+    /// assert_eq!(Ok(Some(0)), b.down_x_with(3, &mut Erroring));
+    /// ```
+    pub fn down_x_with<P: OverflowPolicy<T> + ?Sized>(
+        &self,
+        x: u64,
+        policy: &mut P,
+    ) -> Result<Option<T>, BintError> {
+        self.drain_with(x, |bint, drained| policy.down_x(bint, drained))
+    }
+
+    fn drain_with(
+        &self,
+        x: u64,
+        step: impl FnOnce(Bint<T>, u64) -> Result<Bint<T>, BintError>,
+    ) -> Result<Option<T>, BintError> {
+        let capacity = self.capacity.get();
+        let drained = usize::try_from(x).map_or(capacity, |x| x.min(capacity));
+        let steps = u64::try_from(drained).unwrap_or(x);
+        let bint = step(Bint::from(&self.bint_cell), steps)?;
+        self.capacity.set(capacity - drained);
+        self.bint_cell.set_unchecked(bint.value);
+        if steps == x {
+            Ok(Some(bint.value))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reflecting_matches_walk() {
+        for boundary in 1..8_u8 {
+            for start in 0..boundary {
+                for x in 0..40 {
+                    let mut reflecting = Reflecting::new();
+                    let b: Bint = Bint::new_with_value(boundary, start);
+                    let jumped = b.up_x_with(x, &mut reflecting).unwrap();
+
+                    let mut walking = Reflecting::new();
+                    let mut walked = b;
+                    for _ in 0..x {
+                        walked = walked.up_with(&mut walking).unwrap();
+                    }
+                    assert_eq!(walked, jumped);
+                    assert_eq!(walking, reflecting);
+
+                    assert_eq!(Ok(b), jumped.down_x_with(x, &mut reflecting));
+                    assert_eq!(mirror(b, Direction::Down), reflecting.direction());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        let b: Bint<u128> = Bint::new_with_value(u128::MAX, u128::MAX - 2);
+        assert_eq!(
+            Ok(Bint::new_with_value(u128::MAX, u128::MAX - 1)),
+            b.up_x_with(u64::MAX, &mut Saturating)
+        );
+        assert_eq!(
+            Ok(Bint::new_with_value(u128::MAX, u128::MAX - 3)),
+            b.up_x_with(3, &mut Reflecting::new())
+        );
+
+        let zero: Bint = Bint::new(0);
+        assert_eq!(Ok(zero), zero.up_x_with(5, &mut Saturating));
+        assert_eq!(Ok(zero), zero.down_x_with(5, &mut Reflecting::new()));
+        assert_eq!(Err(BintError::WouldWrap), zero.up_with(&mut Erroring));
+    }
+
+    #[test]
+    fn wrapping_matches_plain() {
+        let cell: BintCell = BintCell::new(7);
+        let drainable: DrainableBintCell = DrainableBintCell::new(7, 1_000);
+
+        for x in 0..20 {
+            let expected = Bint::from(&cell).up_x(x).down_x(x / 3);
+            assert_eq!(Ok(expected.value()), {
+                cell.up_x_with(x, &mut Wrapping).unwrap();
+                cell.down_x_with(x / 3, &mut Wrapping)
+            });
+            drainable.up_x_with(x, &mut Wrapping).unwrap();
+            drainable.down_x_with(x / 3, &mut Wrapping).unwrap();
+            assert_eq!(cell.value(), drainable.value());
+        }
+    }
+}
@@ -0,0 +1,245 @@
+//! Mixed radix encoding of several [`Bint`]s into a single index.
+//!
+//! Each `Bint` is a digit whose base is its boundary. The first digit is the least
+//! significant one, matching [`Odometer`](crate::Odometer), so enumerating the indexes from 0
+//! steps the first digit fastest.
+//!
+//! ```
+//! use bint::{radix, Bint};
+//!
+//! let boundaries: [u8; 2] = [6, 10];
+//! let span = radix::span(&boundaries).unwrap();
+//!
+//! for index in 0..span {
+//!     let digits = radix::decode(index, &boundaries).unwrap();
+//!     assert_eq!(Ok(index), radix::encode(&digits));
+//! }
+//! ```
+
+use crate::{Bint, BintError, Unsigned};
+
+/// Encodes the digits into the number of steps an [`Odometer`](crate::Odometer) with the same
+/// boundaries would take to reach them from 0.
+///
+/// # Errors
+///
+/// Returns `BintError::ZeroBoundary` if any of the digits has a zero boundary, and
+/// `BintError::Overflow` if the index doesn't fit in a `u128`.
+///
+/// ```
+/// use bint::{radix, Bint, BintError};
+///
+/// let digits: [Bint; 2] = [Bint::new_with_value(6, 5), Bint::new_with_value(10, 3)];
+/// assert_eq!(Ok(23), radix::encode(&digits));
+///
+/// let huge = [Bint::new_with_value(u128::MAX, 1), Bint::new_with_value(2, 1)];
+/// assert_eq!(Err(BintError::Overflow), radix::encode(&huge));
+/// ```
+pub fn encode<T: Unsigned>(digits: &[Bint<T>]) -> Result<u128, BintError> {
+    encode_wide(digits.iter().map(|digit| widen(*digit)))
+}
+
+/// Decodes an index into digits with the given boundaries. The reverse of [`encode`].
+///
+/// # Errors
+///
+/// Returns `BintError::ZeroBoundary` if any of the boundaries is 0, and
+/// `BintError::ValueOutOfRange` if the index isn't below the [`span`] of the boundaries.
+///
+/// ```
+/// use bint::{radix, Bint, BintError};
+///
+/// let digits: Vec<Bint> = radix::decode(23, &[6, 10]).unwrap();
+/// assert_eq!(vec![Bint::new_with_value(6, 5), Bint::new_with_value(10, 3)], digits);
+///
+/// assert_eq!(
+///     Err(BintError::ValueOutOfRange { value: 60, boundary: 60 }),
+///     radix::decode::<u8>(60, &[6, 10])
+/// );
+/// ```
+pub fn decode<T: Unsigned>(index: u128, boundaries: &[T]) -> Result<Vec<Bint<T>>, BintError> {
+    let wide: Vec<u128> = boundaries
+        .iter()
+        .map(|boundary| boundary.to_u128())
+        .collect();
+    Ok(decode_wide(index, &wide)?
+        .into_iter()
+        .zip(boundaries)
+        .map(|(value, &boundary)| Bint::new_with_value(boundary, T::from_u128(value)))
+        .collect())
+}
+
+/// The number of distinct combinations of digits, which is the product of the boundaries, or
+/// `None` if it doesn't fit in a `u128`.
+///
+/// ```
+/// use bint::radix;
+///
+/// assert_eq!(Some(60), radix::span(&[6_u8, 10]));
+/// assert_eq!(None, radix::span(&[u64::MAX, u64::MAX, 2]));
+/// ```
+#[must_use]
+pub fn span<T: Unsigned>(boundaries: &[T]) -> Option<u128> {
+    span_wide(boundaries.iter().map(|boundary| boundary.to_u128()))
+}
+
+/// Mixed radix encoding for tuples of [`Bint`]s, which can each have their own integer type.
+///
+/// ```
+/// use bint::radix::MixedRadix;
+/// use bint::Bint;
+///
+/// let seat: Bint = Bint::new_with_value(6, 5);
+/// let hand: Bint<u16> = Bint::new_with_value(1_000, 3);
+///
+/// assert_eq!(Ok(23), (seat, hand).to_index());
+/// assert_eq!(Some(6_000), (seat, hand).span());
+///
+/// let (seat, hand) = (seat, hand).at_index(5_995).unwrap();
+/// assert_eq!(1, seat.value());
+/// assert_eq!(999, hand.value());
+/// ```
+pub trait MixedRadix: Sized {
+    /// See [`encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if any of the digits has a zero boundary, and
+    /// `BintError::Overflow` if the index doesn't fit in a `u128`.
+    fn to_index(&self) -> Result<u128, BintError>;
+
+    /// Digits with the same boundaries, decoded from the index. See [`decode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if any of the boundaries is 0, and
+    /// `BintError::ValueOutOfRange` if the index isn't below the span of the boundaries.
+    fn at_index(&self, index: u128) -> Result<Self, BintError>;
+
+    /// See [`span`].
+    fn span(&self) -> Option<u128>;
+}
+
+macro_rules! impl_mixed_radix {
+    ($(($($t:ident $i:tt),+))+) => {
+        $(
+            impl<$($t: Unsigned),+> MixedRadix for ($(Bint<$t>,)+) {
+                fn to_index(&self) -> Result<u128, BintError> {
+                    encode_wide([$(widen(self.$i)),+].iter().copied())
+                }
+
+                fn at_index(&self, index: u128) -> Result<Self, BintError> {
+                    let values = decode_wide(index, &[$(self.$i.boundary().to_u128()),+])?;
+                    Ok(($(
+                        Bint::new_with_value(self.$i.boundary(), $t::from_u128(values[$i])),
+                    )+))
+                }
+
+                fn span(&self) -> Option<u128> {
+                    span_wide([$(self.$i.boundary().to_u128()),+].iter().copied())
+                }
+            }
+        )+
+    };
+}
+
+impl_mixed_radix! {
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+}
+
+fn widen<T: Unsigned>(digit: Bint<T>) -> (u128, u128) {
+    (digit.value().to_u128(), digit.boundary().to_u128())
+}
+
+fn encode_wide<I>(digits: I) -> Result<u128, BintError>
+where
+    I: DoubleEndedIterator<Item = (u128, u128)>,
+{
+    digits.rev().try_fold(0_u128, |index, (value, boundary)| {
+        if boundary == 0 {
+            return Err(BintError::ZeroBoundary);
+        }
+        index
+            .checked_mul(boundary)
+            .and_then(|index| index.checked_add(value))
+            .ok_or(BintError::Overflow)
+    })
+}
+
+fn decode_wide(index: u128, boundaries: &[u128]) -> Result<Vec<u128>, BintError> {
+    if boundaries.contains(&0) {
+        return Err(BintError::ZeroBoundary);
+    }
+    let mut rest = index;
+    let values = boundaries
+        .iter()
+        .map(|boundary| {
+            let value = rest % boundary;
+            rest /= boundary;
+            value
+        })
+        .collect();
+    if rest > 0 {
+        return Err(BintError::ValueOutOfRange {
+            value: index,
+            // The index always fits when the span overflows.
+            boundary: span_wide(boundaries.iter().copied()).unwrap_or(u128::MAX),
+        });
+    }
+    Ok(values)
+}
+
+fn span_wide<I: Iterator<Item = u128>>(mut boundaries: I) -> Option<u128> {
+    boundaries.try_fold(1_u128, u128::checked_mul)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let boundaries: [u16; 3] = [7, 1, 300];
+
+        for index in 0..span(&boundaries).unwrap() {
+            let digits = decode(index, &boundaries).unwrap();
+            assert_eq!(Ok(index), encode(&digits));
+        }
+    }
+
+    #[test]
+    fn tuple_round_trip() {
+        let digits: (Bint<u8>, Bint<u64>, Bint) = (Bint::new(3), Bint::new(4), Bint::new(5));
+
+        for index in 0..60 {
+            let decoded = digits.at_index(index).unwrap();
+            assert_eq!(Ok(index), decoded.to_index());
+        }
+        assert!(digits.at_index(60).is_err());
+    }
+
+    #[test]
+    fn zero_boundary() {
+        let digits: [Bint; 2] = [Bint::new(6), Bint::new(0)];
+
+        assert_eq!(Err(BintError::ZeroBoundary), encode(&digits));
+        assert_eq!(Err(BintError::ZeroBoundary), decode::<u8>(0, &[0]));
+        assert_eq!(Some(0), span::<u8>(&[6, 0]));
+    }
+
+    #[test]
+    fn wide() {
+        let boundaries = [u128::MAX, u128::MAX];
+        let digits = decode(u128::MAX, &boundaries).unwrap();
+
+        assert_eq!(
+            vec![0, 1],
+            digits.iter().map(Bint::value).collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(u128::MAX), encode(&digits));
+        assert_eq!(None, span(&boundaries));
+    }
+}
@@ -0,0 +1,595 @@
+use std::fmt;
+
+use crate::{Bint, BintCell, BintError, Unsigned};
+
+/// `RangedBint`: A bounded integer over an inclusive range that doesn't have to start at 0.
+///
+/// Stored as the range's minimum plus a zero-based [`Bint`] offset, so it wraps from the
+/// maximum back to the minimum exactly like a `Bint` wraps to 0.
+///
+/// The offset's boundary is the number of values in the range, and has to fit in `T`. So, like
+/// a `Bint`, a range can't cover every value of its integer type. `0..=255` is an error for a
+/// `RangedBint<u8>`, and needs a `RangedBint<u16>` instead.
+///
+/// Usage:
+///
+/// ```
+/// use bint::RangedBint;
+///
+/// let die: RangedBint = RangedBint::new(1, 6).unwrap();
+///
+/// assert_eq!(1, die.value());
+/// assert_eq!(6, die.down().value());
+/// assert_eq!(3, die.up_x(8).value());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RangedBint<T: Unsigned = u8> {
+    min: T,
+    offset: Bint<T>,
+}
+
+impl<T: Unsigned> RangedBint<T> {
+    /// Creates a `RangedBint` set to the minimum.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::EmptyRange` if `max` is below `min`, and `BintError::Overflow` if
+    /// the range covers every value of the integer type, since the number of values then
+    /// doesn't fit in it.
+    ///
+    /// ```
+    /// use bint::{BintError, RangedBint};
+    ///
+    /// assert!(RangedBint::<u8>::new(2, 14).is_ok());
+    /// assert_eq!(
+    ///     Err(BintError::EmptyRange { min: 12, max: 1 }),
+    ///     RangedBint::<u8>::new(12, 1)
+    /// );
+    /// assert_eq!(Err(BintError::Overflow), RangedBint::<u8>::new(0, 255));
+    /// ```
+    pub fn new(min: T, max: T) -> Result<RangedBint<T>, BintError> {
+        if max < min {
+            return Err(BintError::EmptyRange {
+                min: min.to_u128(),
+                max: max.to_u128(),
+            });
+        }
+        let span = max - min;
+        if span == T::MAX {
+            return Err(BintError::Overflow);
+        }
+        Ok(RangedBint {
+            min,
+            offset: Bint::new(span + T::ONE),
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Returns the errors of [`RangedBint::new`], and `BintError::OutsideRange` if the value
+    /// isn't within the range.
+    ///
+    /// ```
+    /// use bint::{BintError, RangedBint};
+    ///
+    /// let month: RangedBint = RangedBint::new_with_value(1, 12, 12).unwrap();
+    /// assert_eq!(1, month.up().value());
+    ///
+    /// assert_eq!(
+    ///     Err(BintError::OutsideRange { value: 0, min: 1, max: 12 }),
+    ///     RangedBint::<u8>::new_with_value(1, 12, 0)
+    /// );
+    /// ```
+    pub fn new_with_value(min: T, max: T, value: T) -> Result<RangedBint<T>, BintError> {
+        let ranged = RangedBint::new(min, max)?;
+        if value < min || value > max {
+            return Err(BintError::OutsideRange {
+                value: value.to_u128(),
+                min: min.to_u128(),
+                max: max.to_u128(),
+            });
+        }
+        Ok(RangedBint {
+            min,
+            offset: Bint::new_unchecked(ranged.offset.boundary, value - min),
+        })
+    }
+
+    /// Creates a `RangedBint` from a zero-based offset from the minimum. The reverse of
+    /// [`RangedBint::offset`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ZeroBoundary` if the offset's boundary is 0, and
+    /// `BintError::Overflow` if the maximum doesn't fit in the integer type.
+    ///
+    /// ```
+    /// use bint::{Bint, RangedBint};
+    ///
+    /// let rank: RangedBint = RangedBint::from_offset(2, Bint::new_with_value(13, 12)).unwrap();
+    ///
+    /// assert_eq!(14, rank.value());
+    /// assert_eq!(14, rank.max());
+    /// ```
+    pub fn from_offset(min: T, offset: Bint<T>) -> Result<RangedBint<T>, BintError> {
+        if offset.boundary == T::ZERO {
+            return Err(BintError::ZeroBoundary);
+        }
+        if T::MAX - min < offset.boundary - T::ONE {
+            return Err(BintError::Overflow);
+        }
+        Ok(RangedBint { min, offset })
+    }
+
+    #[must_use]
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    #[must_use]
+    pub fn max(&self) -> T {
+        self.min + (self.offset.boundary - T::ONE)
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.min + self.offset.value
+    }
+
+    /// The zero-based offset from the minimum, whose boundary is the number of values in the
+    /// range.
+    ///
+    /// ```
+    /// use bint::{Bint, RangedBint};
+    ///
+    /// let die: RangedBint = RangedBint::new_with_value(1, 6, 4).unwrap();
+    ///
+    /// assert_eq!(Bint::new_with_value(6, 3), die.offset());
+    /// ```
+    #[must_use]
+    pub fn offset(&self) -> Bint<T> {
+        self.offset
+    }
+
+    /// ```
+    /// use bint::RangedBint;
+    ///
+    /// let die: RangedBint = RangedBint::new_with_value(1, 6, 5).unwrap();
+    ///
+    /// let die: RangedBint = die.up();
+    /// assert_eq!(6, die.value());
+    ///
+    /// let die: RangedBint = die.up();
+    /// assert_eq!(1, die.value());
+    /// ```
+    #[must_use]
+    pub fn up(&self) -> RangedBint<T> {
+        self.with_offset(self.offset.up())
+    }
+
+    /// ```
+    /// use bint::RangedBint;
+    ///
+    /// let die: RangedBint = RangedBint::new_with_value(1, 6, 2).unwrap();
+    ///
+    /// let die: RangedBint = die.down();
+    /// assert_eq!(1, die.value());
+    ///
+    /// let die: RangedBint = die.down();
+    /// assert_eq!(6, die.value());
+    /// ```
+    #[must_use]
+    pub fn down(&self) -> RangedBint<T> {
+        self.with_offset(self.offset.down())
+    }
+
+    /// ```
+    /// use bint::RangedBint;
+    ///
+    /// let month: RangedBint = RangedBint::new_with_value(1, 12, 11).unwrap();
+    ///
+    /// assert_eq!(2, month.up_x(3).value());
+    /// assert_eq!(11, month.up_x(12_000_000_000).value());
+    /// ```
+    #[must_use]
+    pub fn up_x(self, x: u64) -> RangedBint<T> {
+        self.with_offset(self.offset.up_x(x))
+    }
+
+    /// ```
+    /// use bint::RangedBint;
+    ///
+    /// let month: RangedBint = RangedBint::new_with_value(1, 12, 2).unwrap();
+    ///
+    /// assert_eq!(11, month.down_x(3).value());
+    /// assert_eq!(1, month.down_x(12_000_000_001).value());
+    /// ```
+    #[must_use]
+    pub fn down_x(self, x: u64) -> RangedBint<T> {
+        self.with_offset(self.offset.down_x(x))
+    }
+
+    /// Moves up for positive values of x and down for negative ones.
+    ///
+    /// ```
+    /// use bint::RangedBint;
+    ///
+    /// let rank: RangedBint = RangedBint::new_with_value(2, 14, 3).unwrap();
+    ///
+    /// assert_eq!(14, rank.step(-2).value());
+    /// assert_eq!(5, rank.step(2).value());
+    /// ```
+    #[must_use]
+    pub fn step(self, x: i64) -> RangedBint<T> {
+        self.with_offset(self.offset.step(x))
+    }
+
+    fn with_offset(self, offset: Bint<T>) -> RangedBint<T> {
+        RangedBint {
+            min: self.min,
+            offset,
+        }
+    }
+}
+
+impl<T: Unsigned> fmt::Display for RangedBint<T> {
+    /// Writes the value.
+    ///
+    /// ```
+    /// use bint::RangedBint;
+    ///
+    /// let rank: RangedBint = RangedBint::new_with_value(2, 14, 12).unwrap();
+    ///
+    /// assert_eq!("12", rank.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<T: Unsigned> From<RangedBint<T>> for Bint<T> {
+    /// The zero-based offset. See [`RangedBint::offset`].
+    ///
+    /// ```
+    /// use bint::{Bint, RangedBint};
+    ///
+    /// let month: RangedBint = RangedBint::new_with_value(1, 12, 12).unwrap();
+    ///
+    /// assert_eq!(Bint::new_with_value(12, 11), Bint::from(month));
+    /// ```
+    fn from(ranged: RangedBint<T>) -> Bint<T> {
+        ranged.offset
+    }
+}
+
+impl<T: Unsigned> From<&RangedBintCell<T>> for RangedBint<T> {
+    /// ```
+    /// use bint::{RangedBint, RangedBintCell};
+    ///
+    /// let cell: RangedBintCell = RangedBintCell::new_with_value(1, 6, 4).unwrap();
+    ///
+    /// assert_eq!(RangedBint::new_with_value(1, 6, 4), Ok(RangedBint::from(&cell)));
+    /// ```
+    fn from(cell: &RangedBintCell<T>) -> RangedBint<T> {
+        RangedBint {
+            min: cell.min,
+            offset: Bint::from(&cell.offset),
+        }
+    }
+}
+
+/// `RangedBintCell`: A [`RangedBint`] captured in a [`BintCell`], so it can move through a
+/// shared reference.
+///
+/// Usage:
+///
+/// ```
+/// use bint::RangedBintCell;
+///
+/// let month: RangedBintCell = RangedBintCell::new(1, 12).unwrap();
+///
+/// assert_eq!(12, month.down());
+/// assert_eq!(2, month.up_x(2));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RangedBintCell<T: Unsigned = u8> {
+    min: T,
+    offset: BintCell<T>,
+}
+
+impl<T: Unsigned> RangedBintCell<T> {
+    /// Creates a `RangedBintCell` set to the minimum.
+    ///
+    /// # Errors
+    ///
+    /// See [`RangedBint::new`].
+    ///
+    /// ```
+    /// use bint::{BintError, RangedBintCell};
+    ///
+    /// let rank: RangedBintCell = RangedBintCell::new(2, 14).unwrap();
+    /// assert_eq!(2, rank.value());
+    ///
+    /// assert_eq!(Err(BintError::Overflow), RangedBintCell::<u8>::new(0, 255));
+    /// ```
+    pub fn new(min: T, max: T) -> Result<RangedBintCell<T>, BintError> {
+        RangedBint::new(min, max).map(RangedBintCell::from)
+    }
+
+    /// # Errors
+    ///
+    /// See [`RangedBint::new_with_value`].
+    ///
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let month: RangedBintCell = RangedBintCell::new_with_value(1, 12, 12).unwrap();
+    /// assert_eq!(1, month.up());
+    ///
+    /// assert!(RangedBintCell::<u8>::new_with_value(1, 12, 13).is_err());
+    /// ```
+    pub fn new_with_value(min: T, max: T, value: T) -> Result<RangedBintCell<T>, BintError> {
+        RangedBint::new_with_value(min, max, value).map(RangedBintCell::from)
+    }
+
+    #[must_use]
+    pub fn min(&self) -> T {
+        self.min
+    }
+
+    #[must_use]
+    pub fn max(&self) -> T {
+        RangedBint::from(self).max()
+    }
+
+    #[must_use]
+    pub fn value(&self) -> T {
+        self.min + self.offset.value()
+    }
+
+    /// The zero-based offset from the minimum. See [`RangedBint::offset`].
+    ///
+    /// ```
+    /// use bint::{Bint, RangedBintCell};
+    ///
+    /// let rank: RangedBintCell = RangedBintCell::new_with_value(2, 14, 5).unwrap();
+    ///
+    /// assert_eq!(Bint::new_with_value(13, 3), rank.offset());
+    /// ```
+    #[must_use]
+    pub fn offset(&self) -> Bint<T> {
+        Bint::from(&self.offset)
+    }
+
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let die: RangedBintCell = RangedBintCell::new_with_value(1, 6, 5).unwrap();
+    ///
+    /// assert_eq!(6, die.up());
+    /// assert_eq!(1, die.up());
+    /// ```
+    pub fn up(&self) -> T {
+        self.min + self.offset.up()
+    }
+
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let die: RangedBintCell = RangedBintCell::new_with_value(1, 6, 2).unwrap();
+    ///
+    /// assert_eq!(1, die.down());
+    /// assert_eq!(6, die.down());
+    /// ```
+    pub fn down(&self) -> T {
+        self.min + self.offset.down()
+    }
+
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let month: RangedBintCell = RangedBintCell::new_with_value(1, 12, 11).unwrap();
+    ///
+    /// assert_eq!(2, month.up_x(3));
+    /// assert_eq!(2, month.up_x(12_000_000_000));
+    /// ```
+    pub fn up_x(&self, x: u64) -> T {
+        self.min + self.offset.up_x(x)
+    }
+
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let month: RangedBintCell = RangedBintCell::new_with_value(1, 12, 2).unwrap();
+    ///
+    /// assert_eq!(11, month.down_x(3));
+    /// assert_eq!(10, month.down_x(12_000_000_001));
+    /// ```
+    pub fn down_x(&self, x: u64) -> T {
+        self.min + self.offset.down_x(x)
+    }
+
+    /// Moves up for positive values of x and down for negative ones.
+    ///
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let rank: RangedBintCell = RangedBintCell::new_with_value(2, 14, 3).unwrap();
+    ///
+    /// assert_eq!(14, rank.step(-2));
+    /// assert_eq!(3, rank.step(2));
+    /// ```
+    pub fn step(&self, x: i64) -> T {
+        self.min + self.offset.step(x)
+    }
+
+    /// Sets the value back to the minimum.
+    ///
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let month: RangedBintCell = RangedBintCell::new_with_value(1, 12, 9).unwrap();
+    /// month.reset();
+    ///
+    /// assert_eq!(1, month.value());
+    /// ```
+    pub fn reset(&self) {
+        self.offset.reset();
+    }
+
+    /// Like [`BintCell::set`], a value outside of the range resets the cell, here to the
+    /// minimum.
+    ///
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let die: RangedBintCell = RangedBintCell::new(1, 6).unwrap();
+    ///
+    /// die.set(6);
+    /// assert_eq!(6, die.value());
+    ///
+    /// die.set(7);
+    /// assert_eq!(1, die.value());
+    /// ```
+    pub fn set(&self, value: T) {
+        if self.try_set(value).is_err() {
+            self.reset();
+        }
+    }
+
+    /// Leaves the cell untouched when the value is outside of the range.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::OutsideRange` if the value isn't within the range.
+    ///
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let die: RangedBintCell = RangedBintCell::new(1, 6).unwrap();
+    ///
+    /// assert!(die.try_set(6).is_ok());
+    /// assert!(die.try_set(0).is_err());
+    /// assert_eq!(6, die.value());
+    /// ```
+    pub fn try_set(&self, value: T) -> Result<(), BintError> {
+        let ranged = RangedBint::new_with_value(self.min, self.max(), value)?;
+        self.offset.set(ranged.offset.value);
+        Ok(())
+    }
+}
+
+impl<T: Unsigned> fmt::Display for RangedBintCell<T> {
+    /// Writes the value, like [`RangedBint`].
+    ///
+    /// ```
+    /// use bint::RangedBintCell;
+    ///
+    /// let month: RangedBintCell = RangedBintCell::new_with_value(1, 12, 7).unwrap();
+    ///
+    /// assert_eq!("7", month.to_string());
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<T: Unsigned> From<RangedBint<T>> for RangedBintCell<T> {
+    /// ```
+    /// use bint::{RangedBint, RangedBintCell};
+    ///
+    /// let die: RangedBint = RangedBint::new_with_value(1, 6, 3).unwrap();
+    ///
+    /// assert_eq!(4, RangedBintCell::from(die).up());
+    /// ```
+    fn from(ranged: RangedBint<T>) -> RangedBintCell<T> {
+        RangedBintCell {
+            min: ranged.min,
+            offset: BintCell::from(ranged.offset),
+        }
+    }
+}
+
+impl<T: Unsigned> From<RangedBintCell<T>> for RangedBint<T> {
+    /// ```
+    /// use bint::{RangedBint, RangedBintCell};
+    ///
+    /// let cell: RangedBintCell = RangedBintCell::new_with_value(1, 6, 6).unwrap();
+    ///
+    /// assert_eq!(1, RangedBint::from(cell).up().value());
+    /// ```
+    fn from(cell: RangedBintCell<T>) -> RangedBint<T> {
+        RangedBint::from(&cell)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_offset() {
+        let ranged: RangedBint<u16> = RangedBint::new(100, 199).unwrap();
+        let bint: Bint<u16> = Bint::new(100);
+
+        for x in -150..150 {
+            let stepped = ranged.step(x);
+            assert_eq!(bint.step(x).value() + 100, stepped.value());
+            assert_eq!(bint.step(x), Bint::from(stepped));
+            assert_eq!(Ok(stepped), RangedBint::from_offset(100, stepped.offset()));
+        }
+    }
+
+    #[test]
+    fn extremes() {
+        let top: RangedBint = RangedBint::new(1, 255).unwrap();
+        assert_eq!(1, top.down().up().value());
+        assert_eq!(255, top.down().value());
+
+        let single: RangedBint = RangedBint::new(7, 7).unwrap();
+        assert_eq!(7, single.up_x(1_000).value());
+
+        assert_eq!(
+            Err(BintError::Overflow),
+            RangedBint::from_offset(2_u8, Bint::new(255))
+        );
+        assert_eq!(
+            Err(BintError::ZeroBoundary),
+            RangedBint::from_offset(2_u8, Bint::new(0))
+        );
+    }
+
+    #[test]
+    fn cannot_cover_whole_type() {
+        assert_eq!(Err(BintError::Overflow), RangedBint::<u8>::new(0, u8::MAX));
+        assert_eq!(
+            Err(BintError::Overflow),
+            RangedBint::<u64>::new_with_value(0, u64::MAX, 5)
+        );
+        assert_eq!(
+            Err(BintError::Overflow),
+            RangedBintCell::<u16>::new(0, u16::MAX)
+        );
+
+        let widest: RangedBint = RangedBint::new(0, u8::MAX - 1).unwrap();
+        assert_eq!(u8::MAX - 1, widest.down().value());
+        let top: RangedBint = RangedBint::new(1, u8::MAX).unwrap();
+        assert_eq!(u8::MAX, top.down().value());
+        let wide: RangedBint<u16> = RangedBint::new(0, u16::from(u8::MAX)).unwrap();
+        assert_eq!(255, wide.down().value());
+    }
+
+    #[test]
+    fn cell_matches_ranged() {
+        let cell: RangedBintCell = RangedBintCell::new_with_value(2, 14, 14).unwrap();
+        let mut ranged = RangedBint::from(&cell);
+
+        for x in 0..30 {
+            ranged = ranged.up_x(x);
+            assert_eq!(ranged.value(), cell.up_x(x));
+        }
+        cell.reset();
+        assert_eq!(2, cell.value());
+    }
+}
@@ -0,0 +1,377 @@
+use std::ops::{Index, IndexMut};
+
+use crate::unsigned::private::Sealed as _;
+use crate::{Bint, BintCell, BintError, Unsigned};
+
+/// Ring: A collection of items with a cursor that wraps around them.
+///
+/// The cursor is a [`BintCell`] whose boundary is always the number of items, so it moves
+/// through a shared reference, just like a `BintCell` on its own.
+///
+/// Usage:
+///
+/// ```
+/// use bint::Ring;
+///
+/// let table = Ring::new(vec!["Alice", "Bob", "Carol"]);
+///
+/// assert_eq!(Some(&"Alice"), table.current());
+/// assert_eq!(Some(&"Bob"), table.advance());
+/// assert_eq!(Some(&"Alice"), table.peek(-1));
+/// assert_eq!(Some(&"Carol"), table.advance());
+/// assert_eq!(Some(&"Alice"), table.advance());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ring<T> {
+    items: Vec<T>,
+    cursor: BintCell<usize>,
+}
+
+impl<T> Ring<T> {
+    /// Creates a ring with the cursor on the first item.
+    #[must_use]
+    pub fn new(items: Vec<T>) -> Ring<T> {
+        Ring {
+            cursor: BintCell::new(items.len()),
+            items,
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The items, in order from the first one regardless of the cursor.
+    #[must_use]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    #[must_use]
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// The cursor's position, which can be used to index the ring.
+    ///
+    /// ```
+    /// use bint::Ring;
+    ///
+    /// let ring = Ring::new(vec!['a', 'b', 'c']);
+    /// let first = ring.position();
+    /// ring.retreat();
+    ///
+    /// assert_eq!(2, ring.position().value());
+    /// assert_eq!('a', ring[first]);
+    /// assert_eq!('b', ring[first.up()]);
+    /// ```
+    #[must_use]
+    pub fn position(&self) -> Bint<usize> {
+        Bint::from(&self.cursor)
+    }
+
+    /// The item under the cursor, or `None` if the ring is empty.
+    #[must_use]
+    pub fn current(&self) -> Option<&T> {
+        self.items.get(self.cursor.value())
+    }
+
+    /// ```
+    /// use bint::Ring;
+    ///
+    /// let mut ring = Ring::new(vec![1, 2, 3]);
+    /// ring.advance();
+    ///
+    /// if let Some(item) = ring.current_mut() {
+    ///     *item *= 10;
+    /// }
+    /// assert_eq!(&[1, 20, 3], ring.items());
+    /// ```
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.items.get_mut(self.cursor.value())
+    }
+
+    /// Moves the cursor to the next item, wrapping to the first one, and returns it.
+    pub fn advance(&self) -> Option<&T> {
+        self.cursor.up();
+        self.current()
+    }
+
+    /// Moves the cursor to the previous item, wrapping to the last one, and returns it.
+    pub fn retreat(&self) -> Option<&T> {
+        self.cursor.down();
+        self.current()
+    }
+
+    /// Moves the cursor to the next item the predicate accepts, checking each item at most
+    /// once and the current one last. Returns `None`, leaving the cursor in place, if no item
+    /// is accepted.
+    ///
+    /// ```
+    /// use bint::Ring;
+    ///
+    /// let table = Ring::new(vec![("Alice", true), ("Bob", false), ("Carol", true)]);
+    ///
+    /// assert_eq!(Some(&("Carol", true)), table.advance_until(|&(_, active)| active));
+    /// assert_eq!(Some(&("Alice", true)), table.advance_until(|&(_, active)| active));
+    /// assert_eq!(None, table.advance_until(|&(name, _)| name == "Dave"));
+    /// ```
+    pub fn advance_until<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        self.cursor
+            .up_until(|position| predicate(&self.items[position]))?;
+        self.current()
+    }
+
+    /// Moves the cursor to the previous item the predicate accepts, checking each item at most
+    /// once and the current one last. Returns `None`, leaving the cursor in place, if no item
+    /// is accepted.
+    pub fn retreat_until<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<&T> {
+        self.cursor
+            .down_until(|position| predicate(&self.items[position]))?;
+        self.current()
+    }
+
+    /// Inserts an item at `index`, shifting the items after it along. The cursor stays on the
+    /// same item.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if `index` is greater than the number of items,
+    /// leaving the ring untouched.
+    ///
+    /// ```
+    /// use bint::{BintError, Ring};
+    ///
+    /// let mut table = Ring::new(vec!["Alice", "Bob"]);
+    /// table.advance();
+    /// table.insert(0, "Carol").unwrap();
+    ///
+    /// assert_eq!(&["Carol", "Alice", "Bob"], table.items());
+    /// assert_eq!(Some(&"Bob"), table.current());
+    /// assert_eq!(
+    ///     Err(BintError::ValueOutOfRange { value: 5, boundary: 4 }),
+    ///     table.insert(5, "Dave")
+    /// );
+    /// ```
+    pub fn insert(&mut self, index: usize, item: T) -> Result<(), BintError> {
+        self.cursor.insert_at(index)?;
+        self.items.insert(index, item);
+        Ok(())
+    }
+
+    /// Removes and returns the item at `index`. The cursor stays on the same item, or moves to
+    /// the next one if its item is removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if `index` isn't below the number of items,
+    /// leaving the ring untouched.
+    ///
+    /// ```
+    /// use bint::{BintError, Ring};
+    ///
+    /// let mut table = Ring::new(vec!["Alice", "Bob", "Carol"]);
+    /// table.advance();
+    ///
+    /// assert_eq!(Ok("Bob"), table.remove(1));
+    /// assert_eq!(Some(&"Carol"), table.current());
+    /// assert_eq!(
+    ///     Err(BintError::ValueOutOfRange { value: 2, boundary: 2 }),
+    ///     table.remove(2)
+    /// );
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Result<T, BintError> {
+        self.cursor.remove_at(index)?;
+        Ok(self.items.remove(index))
+    }
+
+    /// The item `offset` places away from the cursor, without moving it. Negative offsets
+    /// look backwards.
+    ///
+    /// ```
+    /// use bint::Ring;
+    ///
+    /// let ring = Ring::new(vec!['a', 'b', 'c']);
+    ///
+    /// assert_eq!(Some(&'c'), ring.peek(-1));
+    /// assert_eq!(Some(&'b'), ring.peek(7));
+    /// assert_eq!(Some(&'a'), ring.current());
+    /// ```
+    #[must_use]
+    pub fn peek(&self, offset: i64) -> Option<&T> {
+        self.items.get(self.position().step(offset).value())
+    }
+
+    /// Every item once, starting at the cursor and going around.
+    ///
+    /// ```
+    /// use bint::Ring;
+    ///
+    /// let ring = Ring::new(vec!['a', 'b', 'c']);
+    /// ring.advance();
+    ///
+    /// assert_eq!(vec!['b', 'c', 'a'], ring.iter().copied().collect::<Vec<_>>());
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        self.position()
+            .lap()
+            .map(move |position| &self.items[position.value()])
+    }
+
+    /// The index of a position, checking that it has the ring's boundary.
+    fn checked_index<U: Unsigned>(&self, position: Bint<U>) -> usize {
+        assert_eq!(
+            self.items.len().to_u128(),
+            position.boundary().to_u128(),
+            "cannot index a Ring with a Bint of a different boundary"
+        );
+        usize::from_u128(position.value().to_u128())
+    }
+}
+
+impl<T> From<Vec<T>> for Ring<T> {
+    fn from(items: Vec<T>) -> Ring<T> {
+        Ring::new(items)
+    }
+}
+
+impl<T, U: Unsigned> Index<Bint<U>> for Ring<T> {
+    type Output = T;
+
+    /// Any width of `Bint` can index a ring, as long as its boundary is the number of items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Bint`'s boundary isn't the number of items.
+    ///
+    /// ```
+    /// use bint::{Bint, Ring};
+    ///
+    /// let ring = Ring::new(vec!['a', 'b', 'c']);
+    /// let last: Bint = Bint::new_with_value(3, 2);
+    ///
+    /// assert_eq!('c', ring[last]);
+    /// ```
+    fn index(&self, position: Bint<U>) -> &T {
+        &self.items[self.checked_index(position)]
+    }
+}
+
+impl<T, U: Unsigned> IndexMut<Bint<U>> for Ring<T> {
+    /// # Panics
+    ///
+    /// Panics if the `Bint`'s boundary isn't the number of items.
+    fn index_mut(&mut self, position: Bint<U>) -> &mut T {
+        let index = self.checked_index(position);
+        &mut self.items[index]
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        let mut ring: Ring<u8> = Ring::new(vec![]);
+
+        assert_eq!(None, ring.current());
+        assert_eq!(None, ring.advance());
+        assert_eq!(None, ring.retreat());
+        assert_eq!(None, ring.peek(3));
+        assert_eq!(None, ring.current_mut());
+        assert_eq!(0, ring.iter().len());
+    }
+
+    #[test]
+    fn walk_around() {
+        let ring = Ring::from(vec![0, 1, 2, 3, 4]);
+
+        for i in 1..=10 {
+            assert_eq!(Some(&(i % 5)), ring.advance());
+            assert_eq!(ring.peek(1), ring.peek(-4));
+        }
+        for i in (0..10).rev() {
+            assert_eq!(Some(&(i % 5)), ring.retreat());
+        }
+    }
+
+    #[test]
+    fn until_skips() {
+        let ring = Ring::new(vec![0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(Some(&3), ring.advance_until(|i| i % 3 == 0));
+        assert_eq!(Some(&0), ring.advance_until(|i| i % 3 == 0));
+        assert_eq!(Some(&4), ring.retreat_until(|&i| i > 3 && i < 5));
+        assert_eq!(Some(&4), ring.retreat_until(|&i| i == 4));
+        assert_eq!(None, ring.retreat_until(|&i| i > 5));
+        assert_eq!(Some(&4), ring.current());
+    }
+
+    #[test]
+    fn shrink_to_empty() {
+        let mut ring = Ring::new(vec![0, 1, 2]);
+        ring.retreat();
+
+        assert_eq!(Ok(2), ring.remove(2));
+        assert_eq!(Some(&0), ring.current());
+        assert_eq!(Ok(0), ring.remove(0));
+        assert_eq!(Ok(1), ring.remove(0));
+        assert_eq!(None, ring.current());
+        assert!(ring.remove(0).is_err());
+
+        ring.insert(0, 3).unwrap();
+        assert_eq!(Some(&3), ring.current());
+        assert_eq!(Some(&3), ring.advance());
+    }
+
+    #[test]
+    fn insert_out_of_bounds() {
+        let mut ring = Ring::new(vec![0, 1, 2]);
+        ring.advance();
+
+        assert_eq!(
+            Err(BintError::ValueOutOfRange {
+                value: 4,
+                boundary: 4
+            }),
+            ring.insert(4, 4)
+        );
+        assert_eq!(&[0, 1, 2], ring.items());
+        assert_eq!(Some(&1), ring.current());
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut ring = Ring::new(vec![0, 1, 2]);
+        let last = ring.position().down();
+        ring[last] = 5;
+        ring[Bint::<u8>::new(3)] = 4;
+        ring[Bint::<u128>::new_with_value(3, 1)] = 3;
+
+        assert_eq!(&[4, 3, 5], ring.items());
+    }
+
+    #[test]
+    fn index_any_width() {
+        let ring = Ring::new(vec![0, 1, 2]);
+
+        assert_eq!(2, ring[Bint::<u8>::new_with_value(3, 2)]);
+        assert_eq!(1, ring[Bint::<u16>::new_with_value(3, 1)]);
+        assert_eq!(0, ring[Bint::<u64>::new(3)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "different boundary")]
+    fn index_different_boundary() {
+        let ring = Ring::new(vec![0, 1, 2]);
+        let _ = ring[Bint::<u8>::new(4)];
+    }
+}
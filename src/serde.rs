@@ -0,0 +1,174 @@
+//! Serde support, behind the `serde` feature.
+//!
+//! Each type serializes as a struct of its value and boundary, plus the remaining capacity for
+//! [`DrainableBintCell`], along with its original capacity when that differs and its maximum
+//! capacity when it has one. Deserializing goes through the `try_new` constructors, so a zero
+//! boundary or a value that's out of range is an error rather than an invalid counter.
+//! Serializing a zero boundary, which `new(0)` can build, is an error too, so that nothing is
+//! written that can't be read back. The text format can't fail while writing, so it reads
+//! `0/0` back as `new(0)` instead; either way, whatever is written can be read back.
+//!
+//! ```
+//! use bint::{Bint, DrainableBintCell};
+//!
+//! let b: Bint = Bint::new_with_value(6, 4);
+//! let json = serde_json::to_string(&b).unwrap();
+//!
+//! assert_eq!(r#"{"value":4,"boundary":6}"#, json);
+//! assert_eq!(b, serde_json::from_str(&json).unwrap());
+//!
+//! assert!(serde_json::from_str::<Bint>(r#"{"value":6,"boundary":6}"#).is_err());
+//! assert!(serde_json::to_string(&Bint::<u8>::new(0)).is_err());
+//! assert!(serde_json::from_str::<DrainableBintCell>(
+//!     r#"{"value":0,"boundary":0,"capacity":4}"#
+//! )
+//! .is_err());
+//! ```
+
+use ::serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Bint, BintCell, BintError, DrainableBintCell, Unsigned};
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "Bint")]
+struct BintRepr<T> {
+    value: T,
+    boundary: T,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "DrainableBintCell")]
+struct DrainableRepr<T> {
+    value: T,
+    boundary: T,
+    capacity: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    original_capacity: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_capacity: Option<usize>,
+}
+
+/// Rejects a zero boundary, which deserializing would refuse.
+fn serializable_boundary<T: Unsigned, E: ser::Error>(boundary: T) -> Result<T, E> {
+    if boundary == T::ZERO {
+        Err(E::custom(BintError::ZeroBoundary))
+    } else {
+        Ok(boundary)
+    }
+}
+
+impl<T: Unsigned + Serialize> Serialize for Bint<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializable_boundary(self.boundary)?;
+        BintRepr {
+            value: self.value,
+            boundary: self.boundary,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Unsigned + Deserialize<'de>> Deserialize<'de> for Bint<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Bint<T>, D::Error> {
+        let repr = BintRepr::deserialize(deserializer)?;
+        Bint::try_new(repr.boundary, repr.value).map_err(de::Error::custom)
+    }
+}
+
+impl<T: Unsigned + Serialize> Serialize for BintCell<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Bint::from(self).serialize(serializer)
+    }
+}
+
+impl<'de, T: Unsigned + Deserialize<'de>> Deserialize<'de> for BintCell<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BintCell<T>, D::Error> {
+        Bint::deserialize(deserializer).map(BintCell::from)
+    }
+}
+
+impl<T: Unsigned + Serialize> Serialize for DrainableBintCell<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        DrainableRepr {
+            value: self.value(),
+            boundary: serializable_boundary(self.boundary())?,
+            capacity: self.remaining(),
+            original_capacity: Some(self.original_capacity())
+                .filter(|original| *original != self.remaining()),
+            max_capacity: self.max_capacity(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Unsigned + Deserialize<'de>> Deserialize<'de> for DrainableBintCell<T> {
+    fn deserialize<D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DrainableBintCell<T>, D::Error> {
+        let repr = DrainableRepr::deserialize(deserializer)?;
+        let bint = Bint::try_new(repr.boundary, repr.value).map_err(de::Error::custom)?;
+        DrainableBintCell::try_from_parts(
+            BintCell::from(bint),
+            repr.capacity,
+            repr.original_capacity.unwrap_or(repr.capacity),
+            repr.max_capacity,
+        )
+        .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let b: Bint<u128> = Bint::new_with_value(u128::MAX, u128::MAX - 1);
+        let json = serde_json::to_string(&b).unwrap();
+        assert_eq!(b, serde_json::from_str(&json).unwrap());
+
+        let cell: BintCell<u16> = BintCell::new_with_value(1_000, 999);
+        let json = serde_json::to_string(&cell).unwrap();
+        assert_eq!(cell, serde_json::from_str(&json).unwrap());
+
+        let drainable: DrainableBintCell = DrainableBintCell::new_with_value(6, 3, 5);
+        assert_eq!(Some(0), drainable.up());
+        let json = serde_json::to_string(&drainable).unwrap();
+        assert_eq!(
+            r#"{"value":0,"boundary":6,"capacity":2,"original_capacity":3}"#,
+            json
+        );
+        assert_eq!(drainable, serde_json::from_str(&json).unwrap());
+
+        let capped: DrainableBintCell = DrainableBintCell::new_with_max_capacity(6, 3, 5);
+        capped.refill(1);
+        let json = serde_json::to_string(&capped).unwrap();
+        assert_eq!(capped, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn zero_boundary_is_not_serialized() {
+        let error = serde_json::to_string(&Bint::<u8>::new(0)).unwrap_err();
+        assert!(error.to_string().contains("greater than 0"));
+
+        assert!(serde_json::to_string(&BintCell::<u64>::new(0)).is_err());
+        assert!(serde_json::to_string(&DrainableBintCell::<u8>::new(0, 3)).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        let error = serde_json::from_str::<BintCell>(r#"{"value":7,"boundary":6}"#).unwrap_err();
+        assert!(error.to_string().contains("out of range"));
+
+        let error = serde_json::from_str::<Bint>(r#"{"value":0,"boundary":0}"#).unwrap_err();
+        assert!(error.to_string().contains("greater than 0"));
+
+        assert!(serde_json::from_str::<Bint>(r#"{"value":300,"boundary":6}"#).is_err());
+        assert!(serde_json::from_str::<Bint>(r#"{"boundary":6}"#).is_err());
+        assert!(serde_json::from_str::<DrainableBintCell>(
+            r#"{"value":0,"boundary":6,"capacity":4,"max_capacity":3}"#
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,447 @@
+//! Dealer button and blind positions at a poker table.
+//!
+//! A [`Table`] has a fixed number of seats, some of which are active. Each hand, the big
+//! blind moves to the next active seat, the small blind is posted from the seat that was the
+//! big blind, and the button moves to the seat that was the small blind. When a seat empties
+//! this can leave a dead small blind or a dead button, so that no player skips a blind.
+//!
+//! With only two active players the button posts the small blind and acts first before the
+//! flop.
+//!
+//! ```
+//! use bint::table::Table;
+//!
+//! let mut table = Table::new(6);
+//! for seat in 0..4 {
+//!     table.sit(seat).unwrap();
+//! }
+//!
+//! assert!(table.next_hand());
+//! assert_eq!(0, table.button());
+//! assert_eq!(Some(1), table.small_blind());
+//! assert_eq!(Some(2), table.big_blind());
+//! assert_eq!(Some(3), table.under_the_gun());
+//! assert_eq!(vec![1, 2, 3, 0], table.dealing_order());
+//!
+//! table.leave(1).unwrap();
+//! table.next_hand();
+//! assert!(table.has_dead_button());
+//! assert_eq!(1, table.button());
+//! assert_eq!(Some(2), table.small_blind());
+//! assert_eq!(Some(3), table.big_blind());
+//! ```
+
+use crate::{Bint, BintCell, BintError, Unsigned};
+
+/// The positions in the current hand, fixed when it's dealt.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Hand {
+    /// The seat the small blind is on, even if it's dead.
+    small_blind_seat: usize,
+    small_blind: Option<usize>,
+    big_blind: usize,
+    under_the_gun: usize,
+    dealing_order: Vec<usize>,
+    dead_button: bool,
+}
+
+/// Table: The seats at a poker table and the positions of the current hand.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Table {
+    seats: Vec<bool>,
+    button: BintCell<usize>,
+    hand: Option<Hand>,
+}
+
+impl Table {
+    /// Creates a table of empty seats, with the button on the first one.
+    #[must_use]
+    pub fn new(seat_count: usize) -> Table {
+        Table {
+            seats: vec![false; seat_count],
+            button: BintCell::new(seat_count),
+            hand: None,
+        }
+    }
+
+    #[must_use]
+    pub fn seat_count(&self) -> usize {
+        self.seats.len()
+    }
+
+    #[must_use]
+    pub fn active_count(&self) -> usize {
+        self.seats.iter().filter(|&&active| active).count()
+    }
+
+    #[must_use]
+    pub fn is_active(&self, seat: usize) -> bool {
+        self.seats.get(seat) == Some(&true)
+    }
+
+    /// Makes a seat active. Sitting down takes effect from the next hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if the seat isn't at the table.
+    pub fn sit(&mut self, seat: usize) -> Result<(), BintError> {
+        *self.seat_mut(seat)? = true;
+        Ok(())
+    }
+
+    /// Makes a seat empty. The current hand's positions don't change, and the next hand works
+    /// around the empty seat.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if the seat isn't at the table.
+    pub fn leave(&mut self, seat: usize) -> Result<(), BintError> {
+        *self.seat_mut(seat)? = false;
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn button(&self) -> usize {
+        self.button.value()
+    }
+
+    /// Moves the button to a seat and forgets the current hand, so the next hand has live
+    /// blinds to the left of the button.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BintError::ValueOutOfRange` if the seat isn't at the table.
+    ///
+    /// ```
+    /// use bint::table::Table;
+    ///
+    /// let mut table = Table::new(6);
+    /// for seat in 0..6 {
+    ///     table.sit(seat).unwrap();
+    /// }
+    /// table.move_button_to(4).unwrap();
+    /// table.next_hand();
+    ///
+    /// assert_eq!(Some(5), table.small_blind());
+    /// assert_eq!(Some(0), table.big_blind());
+    /// ```
+    pub fn move_button_to(&mut self, seat: usize) -> Result<(), BintError> {
+        if seat >= self.seats.len() {
+            return Err(self.seat_out_of_range(seat));
+        }
+        self.button.set(seat);
+        self.hand = None;
+        Ok(())
+    }
+
+    /// Moves the button and blinds on for a new hand. Returns false, leaving no hand in
+    /// progress, if fewer than two seats are active.
+    ///
+    /// ```
+    /// use bint::table::Table;
+    ///
+    /// let mut table = Table::new(9);
+    /// table.sit(2).unwrap();
+    /// assert!(!table.next_hand());
+    ///
+    /// table.sit(7).unwrap();
+    /// assert!(table.next_hand());
+    /// assert_eq!(2, table.button());
+    /// assert_eq!(Some(2), table.small_blind());
+    /// assert_eq!(Some(7), table.big_blind());
+    /// assert_eq!(Some(2), table.under_the_gun());
+    ///
+    /// table.next_hand();
+    /// assert_eq!(7, table.button());
+    /// assert_eq!(Some(2), table.big_blind());
+    /// ```
+    pub fn next_hand(&mut self) -> bool {
+        let dealt = if self.active_count() < 2 {
+            None
+        } else {
+            match &self.hand {
+                Some(previous) => self.move_on(previous),
+                None => self
+                    .next_active_from(self.button())
+                    .and_then(|button| self.live(button)),
+            }
+        };
+        self.hand = dealt.map(|(button, hand)| {
+            self.button.set(button);
+            hand
+        });
+        self.hand.is_some()
+    }
+
+    /// The seat posting the small blind, or `None` if there's no hand in progress or the small
+    /// blind is dead.
+    ///
+    /// ```
+    /// use bint::table::Table;
+    ///
+    /// let mut table = Table::new(4);
+    /// for seat in 0..4 {
+    ///     table.sit(seat).unwrap();
+    /// }
+    /// table.next_hand();
+    /// table.leave(2).unwrap();
+    /// table.next_hand();
+    ///
+    /// assert_eq!(1, table.button());
+    /// assert_eq!(None, table.small_blind());
+    /// assert_eq!(Some(3), table.big_blind());
+    /// ```
+    #[must_use]
+    pub fn small_blind(&self) -> Option<usize> {
+        self.hand.as_ref()?.small_blind
+    }
+
+    /// The seat posting the big blind, or `None` if there's no hand in progress.
+    #[must_use]
+    pub fn big_blind(&self) -> Option<usize> {
+        self.hand.as_ref().map(|hand| hand.big_blind)
+    }
+
+    /// The first active seat after the big blind, who acts first before the flop, or `None`
+    /// if there's no hand in progress. Heads-up, this is the button.
+    #[must_use]
+    pub fn under_the_gun(&self) -> Option<usize> {
+        self.hand.as_ref().map(|hand| hand.under_the_gun)
+    }
+
+    /// Whether the button is on a seat that was empty when the current hand was dealt, or
+    /// that is empty now if there's no hand in progress.
+    #[must_use]
+    pub fn has_dead_button(&self) -> bool {
+        match &self.hand {
+            Some(hand) => hand.dead_button,
+            None => !self.is_active(self.button()),
+        }
+    }
+
+    /// The seats dealt into the current hand in the order cards are dealt, starting after the
+    /// button and ending with it. With no hand in progress, these are the active seats.
+    #[must_use]
+    pub fn dealing_order(&self) -> Vec<usize> {
+        match &self.hand {
+            Some(hand) => hand.dealing_order.clone(),
+            None => self.active_order(self.button()),
+        }
+    }
+
+    fn seat_mut(&mut self, seat: usize) -> Result<&mut bool, BintError> {
+        let error = self.seat_out_of_range(seat);
+        self.seats.get_mut(seat).ok_or(error)
+    }
+
+    fn seat_out_of_range(&self, seat: usize) -> BintError {
+        BintError::ValueOutOfRange {
+            value: seat.to_u128(),
+            boundary: self.seats.len().to_u128(),
+        }
+    }
+
+    /// Deals live blinds with the button on the given seat. Returns the button along with the
+    /// hand, so that nothing changes if the deal fails.
+    fn live(&self, button: usize) -> Option<(usize, Hand)> {
+        let small_blind = if self.active_count() == 2 {
+            button
+        } else {
+            self.next_active_after(button)?
+        };
+        self.deal(button, small_blind, self.next_active_after(small_blind)?)
+    }
+
+    fn move_on(&self, previous: &Hand) -> Option<(usize, Hand)> {
+        let big_blind = self.next_active_after(previous.big_blind)?;
+        if self.active_count() == 2 {
+            let small_blind = self.next_active_after(big_blind)?;
+            return self.deal(small_blind, small_blind, big_blind);
+        }
+        if big_blind == previous.small_blind_seat {
+            // Everyone else sat down between the blinds, so nobody can be skipped.
+            return self.live(self.next_active_after(self.button())?);
+        }
+        self.deal(previous.small_blind_seat, previous.big_blind, big_blind)
+    }
+
+    /// Works out the rest of a hand from its button and blinds, against the seats that are
+    /// active now.
+    fn deal(&self, button: usize, small_blind: usize, big_blind: usize) -> Option<(usize, Hand)> {
+        let hand = Hand {
+            small_blind_seat: small_blind,
+            small_blind: Some(small_blind).filter(|&seat| self.is_active(seat)),
+            big_blind,
+            under_the_gun: self.next_active_after(big_blind)?,
+            dealing_order: self.active_order(button),
+            dead_button: !self.is_active(button),
+        };
+        Some((button, hand))
+    }
+
+    /// The active seats, starting after the given one and ending with it.
+    fn active_order(&self, seat: usize) -> Vec<usize> {
+        Bint::new_with_value(self.seats.len(), seat)
+            .up()
+            .lap()
+            .map(|seat| seat.value())
+            .filter(|&seat| self.is_active(seat))
+            .collect()
+    }
+
+    /// The first active seat at or after the given one.
+    fn next_active_from(&self, seat: usize) -> Option<usize> {
+        self.next_active_after(Bint::new_with_value(self.seats.len(), seat).down().value())
+    }
+
+    /// The first active seat after the given one, going all the way around to it.
+    fn next_active_after(&self, seat: usize) -> Option<usize> {
+        Bint::new_with_value(self.seats.len(), seat)
+            .up_until(|seat| self.seats[seat])
+            .map(|seat| seat.value())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn full_table(seat_count: usize) -> Table {
+        let mut table = Table::new(seat_count);
+        for seat in 0..seat_count {
+            table.sit(seat).unwrap();
+        }
+        table
+    }
+
+    #[test]
+    fn button_moves_each_hand() {
+        let mut table = full_table(6);
+
+        for hand in 0..12 {
+            assert!(table.next_hand());
+            assert_eq!(hand % 6, table.button());
+            assert_eq!(Some((hand + 1) % 6), table.small_blind());
+            assert_eq!(Some((hand + 2) % 6), table.big_blind());
+            assert_eq!(Some((hand + 3) % 6), table.under_the_gun());
+            assert_eq!(Some(&table.button()), table.dealing_order().last());
+        }
+    }
+
+    #[test]
+    fn fresh_button_skips_empty_seats() {
+        let mut table = Table::new(6);
+        table.sit(1).unwrap();
+        table.sit(3).unwrap();
+        table.sit(5).unwrap();
+
+        assert!(table.next_hand());
+        assert_eq!(1, table.button());
+        assert_eq!(Some(3), table.small_blind());
+        assert_eq!(Some(5), table.big_blind());
+        assert_eq!(vec![3, 5, 1], table.dealing_order());
+    }
+
+    #[test]
+    fn down_to_heads_up() {
+        let mut table = full_table(3);
+        table.next_hand();
+        table.leave(0).unwrap();
+        table.next_hand();
+
+        assert_eq!(2, table.button());
+        assert_eq!(Some(2), table.small_blind());
+        assert_eq!(Some(1), table.big_blind());
+        assert_eq!(Some(2), table.under_the_gun());
+        assert_eq!(vec![1, 2], table.dealing_order());
+    }
+
+    #[test]
+    fn players_join_between_blinds() {
+        let mut table = Table::new(6);
+        for &seat in &[0, 1, 3, 4] {
+            table.sit(seat).unwrap();
+        }
+        table.next_hand();
+
+        assert_eq!(0, table.button());
+        assert_eq!(Some(1), table.small_blind());
+        assert_eq!(Some(3), table.big_blind());
+        assert_eq!(Some(4), table.under_the_gun());
+        assert_eq!(vec![1, 3, 4, 0], table.dealing_order());
+
+        // The big blind would come round to the small blind, so the blinds are dealt live
+        // from the seat after the button.
+        table.sit(2).unwrap();
+        table.leave(4).unwrap();
+        table.leave(0).unwrap();
+        table.next_hand();
+
+        assert_eq!(1, table.button());
+        assert!(!table.has_dead_button());
+        assert_eq!(Some(2), table.small_blind());
+        assert_eq!(Some(3), table.big_blind());
+        assert_eq!(Some(1), table.under_the_gun());
+        assert_eq!(vec![2, 3, 1], table.dealing_order());
+
+        table.next_hand();
+
+        assert_eq!(2, table.button());
+        assert_eq!(Some(3), table.small_blind());
+        assert_eq!(Some(1), table.big_blind());
+        assert_eq!(Some(2), table.under_the_gun());
+        assert_eq!(vec![3, 1, 2], table.dealing_order());
+    }
+
+    #[test]
+    fn leaving_keeps_the_current_hand() {
+        let mut table = full_table(5);
+        table.next_hand();
+        table.leave(1).unwrap();
+        table.leave(2).unwrap();
+
+        assert_eq!(0, table.button());
+        assert_eq!(Some(1), table.small_blind());
+        assert_eq!(Some(2), table.big_blind());
+        assert_eq!(Some(3), table.under_the_gun());
+        assert!(!table.has_dead_button());
+        assert_eq!(vec![1, 2, 3, 4, 0], table.dealing_order());
+
+        table.next_hand();
+        assert_eq!(1, table.button());
+        assert!(table.has_dead_button());
+        assert_eq!(None, table.small_blind());
+        assert_eq!(Some(3), table.big_blind());
+        assert_eq!(Some(4), table.under_the_gun());
+        assert_eq!(vec![3, 4, 0], table.dealing_order());
+    }
+
+    #[test]
+    fn seats_out_of_range() {
+        let mut table = Table::new(0);
+
+        let out_of_range = Err(BintError::ValueOutOfRange {
+            value: 0,
+            boundary: 0,
+        });
+        assert_eq!(out_of_range, table.sit(0));
+        assert_eq!(out_of_range, table.leave(0));
+        assert_eq!(out_of_range, table.move_button_to(0));
+        assert!(!table.next_hand());
+
+        assert!(table.has_dead_button());
+        assert_eq!(None, table.under_the_gun());
+        assert!(table.dealing_order().is_empty());
+
+        let mut table = full_table(6);
+        assert_eq!(
+            Err(BintError::ValueOutOfRange {
+                value: 6,
+                boundary: 6
+            }),
+            table.move_button_to(6)
+        );
+        assert_eq!(0, table.button());
+    }
+}
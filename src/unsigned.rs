@@ -0,0 +1,94 @@
+use std::fmt;
+use std::hash::Hash;
+use std::num::ParseIntError;
+use std::ops::{Add, Rem, Sub};
+use std::str::FromStr;
+
+pub(crate) mod private {
+    /// Keeps [`Unsigned`](super::Unsigned) from being implemented outside of this crate, and
+    /// holds the conversions that only the crate may use.
+    pub trait Sealed: Sized {
+        /// Narrows a `u128` to this type. Only called with values that fit, which debug builds
+        /// check.
+        fn from_u128(value: u128) -> Self;
+    }
+}
+
+/// The unsigned integer types that can back a [`Bint`](crate::Bint).
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64`, `u128` and `usize`. The trait is sealed, so it
+/// can't be implemented outside of this crate.
+pub trait Unsigned:
+    Copy
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + Eq
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Rem<Output = Self>
+    + FromStr<Err = ParseIntError>
+    + Send
+    + Sync
+    + 'static
+    + private::Sealed
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MAX: Self;
+
+    /// Widens the value to a `u128`.
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl private::Sealed for $t {
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_u128(value: u128) -> Self {
+                    debug_assert!(
+                        value <= Unsigned::to_u128(<$t>::MAX),
+                        "{} doesn't fit in {}",
+                        value,
+                        stringify!($t)
+                    );
+                    value as $t
+                }
+            }
+
+            impl Unsigned for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MAX: Self = <$t>::MAX;
+
+                #[allow(clippy::cast_lossless)]
+                fn to_u128(self) -> u128 {
+                    self as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::private::Sealed;
+
+    #[test]
+    fn from_u128_narrows() {
+        assert_eq!(255, u8::from_u128(255));
+        assert_eq!(u64::MAX, u64::from_u128(u128::from(u64::MAX)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "300 doesn't fit in u8")]
+    fn from_u128_checks_fit() {
+        let _ = u8::from_u128(300);
+    }
+}